@@ -0,0 +1,145 @@
+#![allow(clippy::type_complexity)]
+
+use bevy_tween::combinator::AnimationBuilderExt;
+use bevy_tween::interpolation::EaseKind;
+use bevy_tween::prelude::{Interpolator, IntoTarget};
+use bevy_tween::tween::ComponentTween;
+use bevy_tween_helpers::prelude::*;
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::time::Duration;
+
+const TWEEN_DURATION: Duration = Duration::from_secs(5);
+const CONTESTED_TWEENS: u32 = 10;
+
+/// Spawning a handful of tweens with priority should cost the same
+/// no matter how many unrelated tweens already exist
+fn priority_resolution_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("priority resolution with unrelated tweens");
+    for unrelated_tweens in [100, 1_000, 10_000] {
+        group.bench_with_input(
+            BenchmarkId::new("indexed by TweenTargetOf", unrelated_tweens),
+            &unrelated_tweens,
+            |b, unrelated_tweens| {
+                b.iter_batched(
+                    || {
+                        let mut app = make_app(*unrelated_tweens);
+                        app.add_plugins(TweenPriorityHandler::<BenchPolator>::default());
+                        prepare_contested_tweens(app)
+                    },
+                    |mut app| app.update(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("full scan", unrelated_tweens),
+            &unrelated_tweens,
+            |b, unrelated_tweens| {
+                b.iter_batched(
+                    || {
+                        let mut app = make_app(*unrelated_tweens);
+                        app.add_systems(
+                            Update,
+                            full_scan_priority_resolution
+                                .in_set(TweenHelpersSystemSet::PreTargetRemoval),
+                        );
+                        prepare_contested_tweens(app)
+                    },
+                    |mut app| app.update(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn make_app(unrelated_tweens: usize) -> App {
+    let mut app = App::new();
+    app.insert_resource(TweeningLoggingFunction(None));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<BenchPolator>::default(),
+    ));
+    let mut commands = app.world_mut().commands();
+    for _ in 0..unrelated_tweens {
+        let target = commands.spawn(()).id();
+        let mut state = target.into_target().state(());
+        commands.spawn(()).animation().insert(tween_with_priority(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| BenchPolator),
+            1,
+        ));
+    }
+    app
+}
+
+/// Lets the unrelated tweens settle, then spawns the tweens whose resolution is measured
+fn prepare_contested_tweens(mut app: App) -> App {
+    app.update();
+    let mut commands = app.world_mut().commands();
+    let contested_target = commands.spawn(()).id();
+    let mut state = contested_target.into_target().state(());
+    for priority in 0..CONTESTED_TWEENS {
+        commands.spawn(()).animation().insert(tween_with_priority(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| BenchPolator),
+            priority,
+        ));
+    }
+    app
+}
+
+/// Resolution as it was done before `TweenTargetOf` was used as an index:
+/// every newborn tween scans every tween of its type
+fn full_scan_priority_resolution(
+    mut tween_request_writer: MessageWriter<TweenRequest>,
+    all_tweens: Query<(
+        &ComponentTween<BenchPolator>,
+        &TweenPriorityToOthersOfType,
+        Entity,
+    )>,
+    newborn_tweens: Query<
+        (
+            &ComponentTween<BenchPolator>,
+            &TweenPriorityToOthersOfType,
+            Entity,
+        ),
+        Added<ComponentTween<BenchPolator>>,
+    >,
+) {
+    for (newborn_tween, newborn_priority, newborn_tween_entity) in &newborn_tweens {
+        let newborn_targets = get_tween_targets(newborn_tween);
+        for (other_tween, other_priority, other_tween_entity) in &all_tweens {
+            let shared_targets: Vec<Entity> = get_tween_targets(other_tween)
+                .into_iter()
+                .filter(|target| newborn_targets.contains(target))
+                .collect();
+            if other_tween_entity != newborn_tween_entity
+                && !shared_targets.is_empty()
+                && other_priority.0 < newborn_priority.0
+            {
+                tween_request_writer.write(TweenRequest::RemoveEntity(RemoveTweenTargets {
+                    tween_entity: other_tween_entity,
+                    targets_to_remove: shared_targets,
+                    removal_policy: None,
+                }));
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BenchPolator;
+
+impl Interpolator for BenchPolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
+
+criterion_group!(benches, priority_resolution_scaling);
+criterion_main!(benches);
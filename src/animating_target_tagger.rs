@@ -0,0 +1,105 @@
+use crate::{plugin_for_implementors_of_trait, prelude::*};
+
+/// Lives on every entity that at least one tween of type `T` targets,
+/// so that systems can filter with `With<IsAnimating<T>>` or `Without<IsAnimating<T>>`.
+/// Maintained by `AnimatingTargetTagger`
+#[derive(Component, Debug)]
+pub struct IsAnimating<T: Sendable>(PhantomData<T>);
+
+impl<T: Sendable> Default for IsAnimating<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Triggered when a tween of type `T` starts targeting an entity that no other tween of that type targets
+#[derive(Debug, Clone, EntityEvent)]
+pub struct TargetStartedAnimating<T: Sendable> {
+    #[event_target]
+    pub entity: Entity,
+    _phantom: PhantomData<T>,
+}
+
+/// Triggered when the last tween of type `T` targeting an entity stops targeting it
+#[derive(Debug, Clone, EntityEvent)]
+pub struct TargetStoppedAnimating<T: Sendable> {
+    #[event_target]
+    pub entity: Entity,
+    _phantom: PhantomData<T>,
+}
+
+plugin_for_implementors_of_trait!(AnimatingTargetTagger, Sendable);
+
+/// Maintains `IsAnimating<T>` on the targets of tweens of type `T`,
+/// triggering `TargetStartedAnimating` and `TargetStoppedAnimating` as it comes and goes
+impl<T: Sendable> Plugin for AnimatingTargetTagger<T> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<TweenTargetTracker<T>>() {
+            app.add_plugins(TweenTargetTracker::<T>::default());
+        }
+        app.add_systems(
+            Update,
+            tag_animating_targets::<T>
+                .after(TweenHelpersSystemSet::TargetTracking)
+                .in_set(TweenHelpersSystemSet::PreTargetRemoval),
+        );
+    }
+}
+
+fn tag_animating_targets<T: Sendable>(
+    changed_targets: Query<Entity, Changed<TweenTargetOf>>,
+    mut untracked_targets: RemovedComponents<TweenTargetOf>,
+    tagged_targets: Query<(), With<IsAnimating<T>>>,
+    target_index: TweenTargetIndex,
+    logging_function: Res<TweeningLoggingFunction>,
+    mut commands: Commands,
+) {
+    for target in &changed_targets {
+        let is_animated = target_index
+            .tweens_of_type_targeting::<T>(target)
+            .next()
+            .is_some();
+        let is_tagged = tagged_targets.contains(target);
+        if is_animated && !is_tagged {
+            if let Some(logger) = logging_function.0 {
+                logger(format!(
+                    "{:?} started animating by tweens of type {}",
+                    target,
+                    std::any::type_name::<T>()
+                ));
+            }
+            commands.entity(target).try_insert(IsAnimating::<T>::default());
+            commands.trigger(TargetStartedAnimating::<T> {
+                entity: target,
+                _phantom: PhantomData,
+            });
+        } else if !is_animated && is_tagged {
+            untag_target::<T>(target, logging_function.0, &mut commands);
+        }
+    }
+    // the targets that lost all of their tweens (of every type), despawned ones aren't tagged anymore
+    for target in untracked_targets.read() {
+        if tagged_targets.contains(target) && !changed_targets.contains(target) {
+            untag_target::<T>(target, logging_function.0, &mut commands);
+        }
+    }
+}
+
+fn untag_target<T: Sendable>(
+    target: Entity,
+    logging_function: Option<fn(String)>,
+    commands: &mut Commands,
+) {
+    if let Some(logger) = logging_function {
+        logger(format!(
+            "{:?} stopped animating by tweens of type {}",
+            target,
+            std::any::type_name::<T>()
+        ));
+    }
+    commands.entity(target).try_remove::<IsAnimating<T>>();
+    commands.trigger(TargetStoppedAnimating::<T> {
+        entity: target,
+        _phantom: PhantomData,
+    });
+}
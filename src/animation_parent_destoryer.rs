@@ -1,69 +1,366 @@
 use crate::plugin_for_implementors_of_trait;
 use crate::prelude::*;
+use bevy::ecs::query::QueryFilter;
+use bevy::ecs::system::SystemParam;
 use bevy_time_runner::TimeRunnerEnded;
 use bevy_tween::bevy_time_runner::TimeRunner;
 use bevy_tween::prelude::ComponentTween;
+use bevy_tween::tween::{TweenInterpolationValue, TweenPreviousValue};
 
 #[derive(Component)]
 pub struct AnimationParentToDestroyIfOnlyHasEventsLeft;
 
-pub struct AnimationParentDestroyerPlugin;
+/// Children of animation parents with this component don't keep their parent alive:
+/// a parent left with only such children (once its last tween is removed) is despawned.
+/// Insert it yourself, or have it inserted through `ResidualTweenChildAppExt::register_residual_tween_child`
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ResidualTweenChild;
+
+pub trait ResidualTweenChildAppExt {
+    /// Tags the children of animation parents that match the filter with `ResidualTweenChild`,
+    /// for example `app.register_residual_tween_child::<With<MyCue>>()`.
+    /// Tagging takes place in `TweenHelpersSystemSet::PreTargetRemoval`
+    fn register_residual_tween_child<F: QueryFilter + 'static>(&mut self) -> &mut Self;
+}
+
+impl ResidualTweenChildAppExt for App {
+    fn register_residual_tween_child<F: QueryFilter + 'static>(&mut self) -> &mut Self {
+        self.add_systems(
+            Update,
+            tag_residual_tween_children::<F>.in_set(TweenHelpersSystemSet::PreTargetRemoval),
+        )
+    }
+}
+
+/// What's done with an animation parent once its `TimeRunner` completes,
+/// parents without it follow `AnimationParentDestroyerPlugin::default_completion_policy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+pub enum OnAnimationComplete {
+    #[default]
+    Despawn,
+    /// The parent is left as is, for example to be replayed later on
+    Keep,
+    /// The parent's time runner is rewound to its start and paused, ready to be unpaused
+    ResetAndPause,
+    /// Like `ResetAndPause`, also triggering `ReturnAnimationParentToPool` on the parent
+    ReturnToPool,
+}
+
+/// Triggered on completed animation parents with `OnAnimationComplete::ReturnToPool`
+#[derive(Debug, Clone, Copy, EntityEvent)]
+pub struct ReturnAnimationParentToPool {
+    #[event_target]
+    pub entity: Entity,
+}
+
+/// Sent as a message and triggered on the animation parent right before this crate despawns it
+#[derive(Debug, Clone, Message, EntityEvent)]
+pub struct AnimationParentDespawned {
+    #[event_target]
+    pub entity: Entity,
+    pub reason: AnimationParentDespawnReason,
+    pub name: Option<Name>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationParentDespawnReason {
+    /// Its time runner completed (or it completed and its pool was full, see `AnimationParentPool`)
+    Completed,
+    /// All of its tweens were despawned, for example because their targets were despawned
+    NoChildrenLeft,
+    /// It was tagged with `AnimationParentToDestroyIfOnlyHasEventsLeft` and only had event tweens left
+    OnlyEventsLeft,
+    /// It only had children with `ResidualTweenChild` left
+    OnlyResidualChildrenLeft,
+}
+
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct DefaultAnimationCompletionPolicy(pub OnAnimationComplete);
+
+#[derive(Debug, Default)]
+pub struct AnimationParentDestroyerPlugin {
+    /// Applied to completed animation parents that don't have an `OnAnimationComplete` of their own
+    pub default_completion_policy: OnAnimationComplete,
+}
 
 impl Plugin for AnimationParentDestroyerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, despawn_done_time_runners);
+        app.insert_resource(DefaultAnimationCompletionPolicy(
+            self.default_completion_policy,
+        ))
+        .add_message::<AnimationParentDespawned>()
+        .add_systems(Update, despawn_done_time_runners);
     }
 }
 
 plugin_for_implementors_of_trait!(AnimationParentDestroyerGenericPlugin, Sendable);
 
+/// Despawns animation parents left without tweens of type `T`, see `TypeErasedAnimationParentDestroyerPlugin`
+/// to cover tweens of all types instead
 impl<T: Sendable> Plugin for AnimationParentDestroyerGenericPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.add_observer(despawn_time_runners_with_no_children::<T>);
+        app.add_message::<AnimationParentDespawned>()
+            .add_observer(despawn_time_runners_with_no_children::<T>);
+    }
+}
+
+/// Despawns animation parents left without children (other than residual ones) regardless of their interpolator types,
+/// by watching the removal of any child of a `TimeRunner`, so there's no need to register
+/// an `AnimationParentDestroyerGenericPlugin` per interpolator type.
+/// Generic plugins that are registered nonetheless are disabled, their observers returning right away.
+///
+/// Note that it relies on a global `On<Remove, ChildOf>` observer, which runs whenever any entity in the world
+/// loses its parent (including every despawned child), not only tweens. It bails out early for parents without
+/// a `TimeRunner`, but apps that despawn or reparent many children each frame may prefer the generic plugins.
+pub struct TypeErasedAnimationParentDestroyerPlugin;
+
+/// Inserted by `TypeErasedAnimationParentDestroyerPlugin`
+#[derive(Resource, Debug)]
+pub struct TypeErasedAnimationParentDestruction;
+
+impl Plugin for TypeErasedAnimationParentDestroyerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TypeErasedAnimationParentDestruction)
+            .add_message::<AnimationParentDespawned>()
+            .add_observer(despawn_time_runners_left_without_children);
     }
 }
 
+/// Applies the `OnAnimationComplete` policy of completed time runners, despawning them by default
 pub fn despawn_done_time_runners(
     mut time_runner_ended_reader: MessageReader<TimeRunnerEnded>,
+    mut time_runners: Query<(&mut TimeRunner, Option<&OnAnimationComplete>, Option<&Name>)>,
+    children: Query<&Children>,
+    mut previous_values: Query<&mut TweenPreviousValue>,
+    default_completion_policy: Res<DefaultAnimationCompletionPolicy>,
+    mut despawned_parents_writer: MessageWriter<AnimationParentDespawned>,
     mut commands: Commands,
 ) {
     for event in time_runner_ended_reader.read() {
-        if event.is_completed() {
-            if let Ok(mut entity_commands) = commands.get_entity(event.entity) {
-                entity_commands.try_despawn();
+        if !event.is_completed() {
+            continue;
+        }
+        let Ok((mut time_runner, maybe_completion_policy, maybe_name)) =
+            time_runners.get_mut(event.entity)
+        else {
+            continue;
+        };
+        match maybe_completion_policy
+            .copied()
+            .unwrap_or(default_completion_policy.0)
+        {
+            OnAnimationComplete::Despawn => {
+                despawn_animation_parent(
+                    event.entity,
+                    AnimationParentDespawnReason::Completed,
+                    maybe_name,
+                    &mut despawned_parents_writer,
+                    &mut commands,
+                );
             }
+            OnAnimationComplete::Keep => {}
+            OnAnimationComplete::ResetAndPause => {
+                time_runner.set_tick(0.0);
+                time_runner.set_paused(true);
+                rewind_animation_parent_tweens(
+                    event.entity,
+                    &children,
+                    &mut previous_values,
+                    &mut commands,
+                );
+            }
+            OnAnimationComplete::ReturnToPool => {
+                time_runner.set_tick(0.0);
+                time_runner.set_paused(true);
+                rewind_animation_parent_tweens(
+                    event.entity,
+                    &children,
+                    &mut previous_values,
+                    &mut commands,
+                );
+                commands.trigger(ReturnAnimationParentToPool {
+                    entity: event.entity,
+                });
+            }
+        }
+    }
+}
+
+/// Rewinds the tweens of an animation parent whose time runner is set back to its start:
+/// bevy_tween never resets `TweenPreviousValue`, so delta interpolators would otherwise
+/// replay from the value they last ended with (running backwards)
+pub fn rewind_animation_parent_tweens(
+    animation_parent: Entity,
+    children: &Query<&Children>,
+    previous_values: &mut Query<&mut TweenPreviousValue>,
+    commands: &mut Commands,
+) {
+    for tween_entity in children.iter_descendants(animation_parent) {
+        if let Ok(mut previous_value) = previous_values.get_mut(tween_entity) {
+            previous_value.0 = 0.0;
+            commands
+                .entity(tween_entity)
+                .try_remove::<TweenInterpolationValue>();
         }
     }
 }
 
 pub fn despawn_time_runners_with_no_children<T: Sendable>(
     trigger: On<Remove, ComponentTween<T>>,
+    parents: Query<&ChildOf>,
     time_runners: Query<
         (
             &Children,
-            Entity,
             Has<AnimationParentToDestroyIfOnlyHasEventsLeft>,
+            Option<&Name>,
         ),
         With<TimeRunner>,
     >,
-    event_tweens: Query<(), With<EventEmittingTween>>,
+    leftover_children: LeftoverAnimationParentChildren,
+    type_erased_destruction: Option<Res<TypeErasedAnimationParentDestruction>>,
+    mut despawned_parents_writer: MessageWriter<AnimationParentDespawned>,
     mut commands: Commands,
 ) {
-    'time_runners_for: for (
-        time_runner_children,
-        time_runner_entity,
-        should_destroy_if_only_has_events,
-    ) in &time_runners
+    // the type erased observer already covers tweens of all types
+    if type_erased_destruction.is_some() {
+        return;
+    }
+    // only the tween's own animation parent might be left without children
+    let Ok(child_of) = parents.get(trigger.entity) else {
+        return;
+    };
+    let time_runner_entity = child_of.parent();
+    let Ok((time_runner_children, should_destroy_if_only_has_events, maybe_name)) =
+        time_runners.get(time_runner_entity)
+    else {
+        return;
+    };
+    let children_left: Vec<Entity> = time_runner_children
+        .iter()
+        .filter(|child| *child != trigger.entity)
+        .collect();
+    if let Some(reason) =
+        leftover_children.despawn_reason(&children_left, should_destroy_if_only_has_events)
     {
-        for child in time_runner_children.iter() {
-            if child != trigger.entity
-                && (!should_destroy_if_only_has_events || !event_tweens.contains(child))
-            {
-                continue 'time_runners_for;
+        despawn_animation_parent(
+            time_runner_entity,
+            reason,
+            maybe_name,
+            &mut despawned_parents_writer,
+            &mut commands,
+        );
+    }
+}
+
+fn despawn_time_runners_left_without_children(
+    trigger: On<Remove, ChildOf>,
+    parents: Query<&ChildOf>,
+    time_runners: Query<
+        (
+            Option<&Children>,
+            Has<AnimationParentToDestroyIfOnlyHasEventsLeft>,
+            Option<&Name>,
+        ),
+        With<TimeRunner>,
+    >,
+    leftover_children: LeftoverAnimationParentChildren,
+    mut despawned_parents_writer: MessageWriter<AnimationParentDespawned>,
+    mut commands: Commands,
+) {
+    let Ok(child_of) = parents.get(trigger.entity) else {
+        return;
+    };
+    let time_runner_entity = child_of.parent();
+    let Ok((maybe_children, should_destroy_if_only_has_events, maybe_name)) =
+        time_runners.get(time_runner_entity)
+    else {
+        return;
+    };
+    let children_left: Vec<Entity> = maybe_children
+        .into_iter()
+        .flat_map(|time_runner_children| time_runner_children.iter())
+        .filter(|child| *child != trigger.entity)
+        .collect();
+    if let Some(reason) =
+        leftover_children.despawn_reason(&children_left, should_destroy_if_only_has_events)
+    {
+        despawn_animation_parent(
+            time_runner_entity,
+            reason,
+            maybe_name,
+            &mut despawned_parents_writer,
+            &mut commands,
+        );
+    }
+}
+
+/// Tells whether the children an animation parent is left with keep it alive
+#[derive(SystemParam)]
+pub struct LeftoverAnimationParentChildren<'w, 's> {
+    event_tweens: Query<'w, 's, (), With<EventEmittingTween>>,
+    residual_children: Query<'w, 's, (), With<ResidualTweenChild>>,
+}
+
+impl LeftoverAnimationParentChildren<'_, '_> {
+    /// Returns None if any of the children keeps the parent alive
+    pub fn despawn_reason(
+        &self,
+        children_left: &[Entity],
+        should_destroy_if_only_has_events: bool,
+    ) -> Option<AnimationParentDespawnReason> {
+        if children_left.is_empty() {
+            return Some(AnimationParentDespawnReason::NoChildrenLeft);
+        }
+        let mut only_residual_children_left = true;
+        for child in children_left {
+            if self.residual_children.contains(*child) {
+                continue;
             }
+            if should_destroy_if_only_has_events && self.event_tweens.contains(*child) {
+                only_residual_children_left = false;
+                continue;
+            }
+            return None;
         }
-        if let Ok(mut entity_commands) = commands.get_entity(time_runner_entity) {
-            entity_commands.try_despawn();
+        Some(if only_residual_children_left {
+            AnimationParentDespawnReason::OnlyResidualChildrenLeft
+        } else {
+            AnimationParentDespawnReason::OnlyEventsLeft
+        })
+    }
+}
+
+fn tag_residual_tween_children<F: QueryFilter>(
+    untagged_children: Query<(Entity, &ChildOf), (F, Without<ResidualTweenChild>)>,
+    time_runners: Query<(), With<TimeRunner>>,
+    mut commands: Commands,
+) {
+    for (child, child_of) in &untagged_children {
+        if time_runners.contains(child_of.parent()) {
+            commands.entity(child).try_insert(ResidualTweenChild);
         }
     }
 }
+
+/// Announces the despawn through `AnimationParentDespawned` before despawning the parent
+pub fn despawn_animation_parent(
+    animation_parent: Entity,
+    reason: AnimationParentDespawnReason,
+    maybe_name: Option<&Name>,
+    despawned_parents_writer: &mut MessageWriter<AnimationParentDespawned>,
+    commands: &mut Commands,
+) {
+    if commands.get_entity(animation_parent).is_err() {
+        return;
+    }
+    let animation_parent_despawned = AnimationParentDespawned {
+        entity: animation_parent,
+        reason,
+        name: maybe_name.cloned(),
+    };
+    despawned_parents_writer.write(animation_parent_despawned.clone());
+    commands.trigger(animation_parent_despawned);
+    if let Ok(mut entity_commands) = commands.get_entity(animation_parent) {
+        entity_commands.try_despawn();
+    }
+}
@@ -0,0 +1,229 @@
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy_time_runner::TimeRunner;
+use tween::{SkipTween, TweenPreviousValue};
+
+/// Tags an animation parent as recyclable in the pool with the given label, see `AnimationParentRecycler`.
+/// Such parents are returned to their pool once completed, rather than despawned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[require(OnAnimationComplete = OnAnimationComplete::ReturnToPool)]
+pub struct PooledAnimationParent(pub &'static str);
+
+/// Tags the tweens of idle pooled animation parents, which are skipped and unlinked from their targets
+/// (see `TweenTargetIndex`), so that they neither tag, contend for priority over nor get removed from them.
+/// Removed once their parent is acquired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Component)]
+pub struct IdlePooledTween;
+
+/// The idle animation parents of each pool, paused at their start
+#[derive(Resource, Debug, Default)]
+pub struct AnimationParentPool {
+    capacity: usize,
+    idle_parents: HashMap<&'static str, Vec<Entity>>,
+}
+
+impl AnimationParentPool {
+    /// The maximal amount of idle parents per pool label, parents returned to a full pool are despawned
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn idle_count(&self, label: &'static str) -> usize {
+        self.idle_parents.get(label).map_or(0, Vec::len)
+    }
+
+    pub fn is_idle(&self, animation_parent: Entity) -> bool {
+        self.idle_parents
+            .values()
+            .any(|idle_parents| idle_parents.contains(&animation_parent))
+    }
+
+    /// Returns false if the pool is full
+    fn put(&mut self, label: &'static str, animation_parent: Entity) -> bool {
+        let idle_parents = self.idle_parents.entry(label).or_default();
+        if idle_parents.len() >= self.capacity {
+            return false;
+        }
+        if !idle_parents.contains(&animation_parent) {
+            idle_parents.push(animation_parent);
+        }
+        true
+    }
+
+    fn take(&mut self, label: &'static str) -> Option<Entity> {
+        self.idle_parents.get_mut(label)?.pop()
+    }
+}
+
+/// Recycles completed animation parents tagged with `PooledAnimationParent` instead of despawning them,
+/// along with their tweens. Relies on `AnimationParentDestroyerPlugin` (added by `BevyTweenHelpersPlugin`)
+/// to reset and pause them once completed.
+///
+/// Recycled tweens are rebound to their new target by `TweenTargetTracker`, so each interpolator type used by
+/// pooled tweens needs one (it's added by `TweenTargetRemover`, `TweenPriorityHandler` and `AnimatingTargetTagger`,
+/// or can be added on its own). Tweens of untracked types keep animating their former targets.
+pub struct AnimationParentPoolPlugin {
+    /// The maximal amount of idle parents per pool label
+    pub capacity: usize,
+}
+
+impl Default for AnimationParentPoolPlugin {
+    fn default() -> Self {
+        Self { capacity: 64 }
+    }
+}
+
+impl Plugin for AnimationParentPoolPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AnimationParentPool {
+            capacity: self.capacity,
+            idle_parents: HashMap::default(),
+        })
+        .add_message::<AnimationParentDespawned>()
+        .add_observer(return_animation_parent_to_pool);
+    }
+}
+
+/// Takes idle animation parents out of their pool and prewarms pools
+#[derive(SystemParam)]
+pub struct AnimationParentRecycler<'w, 's> {
+    pool: ResMut<'w, AnimationParentPool>,
+    time_runners: Query<'w, 's, &'static mut TimeRunner>,
+    children: Query<'w, 's, &'static Children>,
+    idle_tweens: Query<'w, 's, (), With<IdlePooledTween>>,
+    previous_values: Query<'w, 's, &'static mut TweenPreviousValue>,
+    tween_request_writer: MessageWriter<'w, TweenRequest>,
+    commands: Commands<'w, 's>,
+}
+
+impl AnimationParentRecycler<'_, '_> {
+    /// Takes an idle parent out of the pool, rebinds all of its tweens to the new target and plays it from the start.
+    /// Rebinding goes through `TweenRequest::SetTargets`, so acquire before `TweenHelpersSystemSet::TargetTracking`
+    /// for the tweens to animate the new target in the same frame. Only tweens of types with a `TweenTargetTracker`
+    /// are rebound, see `AnimationParentPoolPlugin`.
+    /// Returns None if the pool has no idle parents left.
+    pub fn acquire(&mut self, label: &'static str, new_target: Entity) -> Option<Entity> {
+        while let Some(animation_parent) = self.pool.take(label) {
+            // idle parents might have been despawned in the meantime
+            let Ok(mut time_runner) = self.time_runners.get_mut(animation_parent) else {
+                continue;
+            };
+            time_runner.set_tick(0.0);
+            time_runner.set_paused(false);
+            rewind_animation_parent_tweens(
+                animation_parent,
+                &self.children,
+                &mut self.previous_values,
+                &mut self.commands,
+            );
+            for tween_entity in self.children.iter_descendants(animation_parent) {
+                if !self.idle_tweens.contains(tween_entity) {
+                    continue;
+                }
+                self.commands
+                    .entity(tween_entity)
+                    .try_remove::<(IdlePooledTween, SkipTween)>();
+                self.tween_request_writer
+                    .write(TweenRequest::SetTargets(SetTweenTargets {
+                        tween_entity,
+                        targets: vec![new_target],
+                    }));
+            }
+            return Some(animation_parent);
+        }
+        None
+    }
+
+    /// Spawns up to `count` idle parents (within the pool's capacity) using `spawn_animation_parent`,
+    /// which should spawn an animation parent with its tweens (targeting any entity) and return it
+    pub fn prewarm(
+        &mut self,
+        label: &'static str,
+        count: usize,
+        mut spawn_animation_parent: impl FnMut(&mut Commands) -> Entity,
+    ) {
+        let count = count.min(
+            self.pool
+                .capacity()
+                .saturating_sub(self.pool.idle_count(label)),
+        );
+        for _ in 0..count {
+            let animation_parent = spawn_animation_parent(&mut self.commands);
+            self.commands
+                .entity(animation_parent)
+                .insert(PooledAnimationParent(label))
+                .queue(|mut entity: EntityWorldMut| {
+                    if let Some(mut time_runner) = entity.get_mut::<TimeRunner>() {
+                        time_runner.set_paused(true);
+                    }
+                });
+            // goes through the same path as completed parents, once its tweens are spawned
+            self.commands.trigger(ReturnAnimationParentToPool {
+                entity: animation_parent,
+            });
+        }
+    }
+}
+
+/// Puts completed pooled animation parents back into their pool
+#[derive(SystemParam)]
+struct AnimationParentReturner<'w, 's> {
+    pooled_parents: Query<'w, 's, &'static PooledAnimationParent>,
+    pool: ResMut<'w, AnimationParentPool>,
+    children: Query<'w, 's, &'static Children>,
+    tweens: Query<'w, 's, (), With<TweenPreviousValue>>,
+    target_index: TweenTargetIndex<'w, 's>,
+}
+
+impl AnimationParentReturner<'_, '_> {
+    /// Returns false if the parent isn't pooled or its pool is full
+    fn put_back(&mut self, animation_parent: Entity, commands: &mut Commands) -> bool {
+        let returned_to_pool = self
+            .pooled_parents
+            .get(animation_parent)
+            .is_ok_and(|pooled_parent| self.pool.put(pooled_parent.0, animation_parent));
+        if !returned_to_pool {
+            return false;
+        }
+        // idle tweens keep their (former) targets, but aren't linked to them until acquired
+        for tween_entity in self.children.iter_descendants(animation_parent) {
+            if !self.tweens.contains(tween_entity) {
+                continue;
+            }
+            commands
+                .entity(tween_entity)
+                .try_insert((IdlePooledTween, SkipTween));
+            for (link, _) in self.target_index.links_of(tween_entity) {
+                commands.entity(link).try_despawn();
+            }
+        }
+        true
+    }
+}
+
+fn return_animation_parent_to_pool(
+    trigger: On<ReturnAnimationParentToPool>,
+    mut returner: AnimationParentReturner,
+    names: Query<&Name>,
+    logging_function: Res<TweeningLoggingFunction>,
+    mut despawned_parents_writer: MessageWriter<AnimationParentDespawned>,
+    mut commands: Commands,
+) {
+    if returner.put_back(trigger.entity, &mut commands) {
+        if let Some(logger) = logging_function.0 {
+            logger(format!(
+                "returned animation parent {:?} to its pool",
+                trigger.entity
+            ));
+        }
+    } else {
+        // there's no pool (or no room in it) to return to
+        despawn_animation_parent(
+            trigger.entity,
+            AnimationParentDespawnReason::Completed,
+            names.get(trigger.entity).ok(),
+            &mut despawned_parents_writer,
+            &mut commands,
+        );
+    }
+}
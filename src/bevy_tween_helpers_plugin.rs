@@ -4,6 +4,10 @@ use crate::prelude::*;
 pub struct BevyTweenHelpersPlugin {
     /// Here you can insert your own function for logging BevyTweenHelpersPlugin
     pub logging_function: Option<fn(String)>,
+    /// What's done with animation parents once they complete, unless they have an `OnAnimationComplete` of their own
+    pub default_animation_completion_policy: OnAnimationComplete,
+    /// Adds `TypeErasedAnimationParentDestroyerPlugin`, which makes `AnimationParentDestroyerGenericPlugin` redundant
+    pub type_erased_animation_parent_destruction: bool,
 }
 
 #[derive(Resource)]
@@ -11,11 +15,16 @@ pub struct TweeningLoggingFunction(pub Option<fn(String)>);
 
 impl Plugin for BevyTweenHelpersPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(TweeningLoggingFunction(self.logging_function.clone()))
+        app.insert_resource(TweeningLoggingFunction(self.logging_function))
             .add_plugins((
                 TweenRequestPlugin,
-                AnimationParentDestroyerPlugin,
+                AnimationParentDestroyerPlugin {
+                    default_completion_policy: self.default_animation_completion_policy,
+                },
                 BevyTweenHelpersSystemSetsPlugin
             ));
+        if self.type_erased_animation_parent_destruction {
+            app.add_plugins(TypeErasedAnimationParentDestroyerPlugin);
+        }
     }
 }
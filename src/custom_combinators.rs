@@ -51,6 +51,31 @@ where
     }
 }
 
+pub fn tween_with_priority_on_channel<I, T>(
+    duration: Duration,
+    interpolation: I,
+    tween: T,
+    priority: u32,
+    channel: &'static str,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    I: Bundle,
+    T: Bundle,
+{
+    move |a, pos| {
+        let start = *pos;
+        let end = start + duration;
+        a.spawn((
+            TimeSpan::try_from(start..end).unwrap(),
+            interpolation,
+            tween,
+            TweenPriorityToOthersOfType(priority),
+            TweenChannel(channel),
+        ));
+        *pos = end;
+    }
+}
+
 pub fn wait_for(wait_duration: Duration) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
     move |a, pos| {
         let start = *pos;
@@ -15,7 +15,7 @@
     * Combining it with `AnimationParentDestroyerGenericPlugin` results in automatic tween and parent clearing
   * `TweenPriorityHandler`
     * Handles `TweenPriorityToOthersOfType`, when tweens or parents have this component,
-    fight against other tweens of that type. The ones with the highest priority will survive.
+      fight against other tweens of that type. The ones with the highest priority will survive.
     * If you're not sure what the previous bullet means, read `TweenPriorityToOthersOfType`'s description
     *
 * I also added my tween combinators, feel free to open PRs requesting to add your own!
@@ -24,7 +24,7 @@
 ### Example
 
 An example for registering the plugins into your app would be:
-```rust
+```rust,ignore
     app.add_plugins((
             DefaultTweenPlugins, //from bevy_tween
             BevyTweenHelpersPlugin::default(),
@@ -40,30 +40,44 @@ An example for registering the plugins into your app would be:
 
 #[macro_use]
 pub mod macros;
+pub mod animating_target_tagger;
 pub mod animation_parent_destoryer;
+pub mod animation_parent_pool;
 pub mod bevy_tween_helpers_plugin;
 pub mod custom_combinators;
 pub mod event_animation_parent_tagger;
 pub mod extra_transform_tween_makers;
 pub mod target_extractor;
 pub mod tween_priority;
+pub mod tween_priority_stack;
+pub mod tween_query;
 pub mod tween_request;
 pub mod tween_skip_tagger;
+pub mod tween_suspension;
+pub mod tween_target_removal_policy;
 pub mod tween_target_remover;
+pub mod tween_target_tracker;
 pub mod utilities;
 
 pub mod prelude {
+    pub use crate::animating_target_tagger::*;
     pub use crate::animation_parent_destoryer::*;
+    pub use crate::animation_parent_pool::*;
     pub use crate::bevy_tween_helpers_plugin::*;
     pub use crate::custom_combinators::*;
     pub use crate::event_animation_parent_tagger::*;
     pub use crate::extra_transform_tween_makers::*;
     pub use crate::target_extractor::*;
     pub use crate::tween_priority::*;
+    pub use crate::tween_priority_stack::*;
+    pub use crate::tween_query::*;
     pub use crate::tween_request::*;
     pub use crate::tween_skip_tagger::*;
+    pub use crate::tween_suspension::*;
+    pub use crate::tween_target_removal_policy::*;
     pub use crate::tween_target_remover::*;
-    pub use crate::utilities::{system_sets::*, trait_unions::*};
+    pub use crate::tween_target_tracker::*;
+    pub use crate::utilities::{system_sets::*, trait_unions::*, tween_time_window::*};
     pub use bevy::{platform::collections::HashMap, prelude::*};
     pub use bevy_tween::*;
     pub use std::marker::PhantomData;
@@ -1,12 +1,58 @@
+use bevy::ecs::system::SystemParam;
 use bevy_tween::prelude::ComponentTween;
-use bevy_tween::tween::TargetComponent;
+use bevy_tween::tween::{AnimationTarget, TargetComponent};
 use crate::prelude::*;
 
 /// extracts the tweens targets as a vec
+/// (only those listed explicitly, see `TweenTargetResolver` for the rest of the variants)
 pub fn get_tween_targets<T: Sendable>(tween: &ComponentTween<T>) -> Vec<Entity>{
     match &tween.target {
         TargetComponent::Entity(dominant_target) => vec![*dominant_target],
         TargetComponent::Entities(dominant_targets) => dominant_targets.clone(),
         _ => Vec::new()
     }
-}
\ No newline at end of file
+}
+
+/// Resolves tween targets to concrete entities, including marker targets
+/// (which are resolved to the nearest entity with an `AnimationTarget`, starting from the tween itself
+/// and going up its `ChildOf` chain, just like bevy_tween does)
+#[derive(SystemParam)]
+pub struct TweenTargetResolver<'w, 's> {
+    ancestry: Query<'w, 's, (Option<&'static ChildOf>, Has<AnimationTarget>)>,
+}
+
+impl TweenTargetResolver<'_, '_> {
+    pub fn resolve<T: Sendable>(
+        &self,
+        tween_entity: Entity,
+        tween: &ComponentTween<T>,
+    ) -> Vec<Entity> {
+        match &tween.target {
+            TargetComponent::Marker { .. } => {
+                self.marked_ancestor_of(tween_entity).into_iter().collect()
+            }
+            _ => get_tween_targets(tween),
+        }
+    }
+
+    /// Replaces a marker target with the entity it currently resolves to,
+    /// so that it can be taken away from the tween or swapped for another
+    pub fn make_explicit<T: Sendable>(&self, tween_entity: Entity, tween: &mut ComponentTween<T>) {
+        if let TargetComponent::Marker { .. } = tween.target
+            && let Some(animation_target) = self.marked_ancestor_of(tween_entity)
+        {
+            tween.target = TargetComponent::Entity(animation_target);
+        }
+    }
+
+    fn marked_ancestor_of(&self, tween_entity: Entity) -> Option<Entity> {
+        let mut current = tween_entity;
+        loop {
+            let (maybe_child_of, has_animation_target) = self.ancestry.get(current).ok()?;
+            if has_animation_target {
+                return Some(current);
+            }
+            current = maybe_child_of?.parent();
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use crate::plugin_for_implementors_of_trait;
 use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashSet;
 use bevy::prelude::Component;
+use bevy_time_runner::{TimeRunner, TimeSpan};
 use bevy_tween::prelude::ComponentTween;
+use std::any::TypeId;
+use std::cmp::Ordering;
 
 /// When there's a conflict between two existing tweens of the same type
 /// (say, two position tweens on the same entity)
@@ -11,117 +16,518 @@ use bevy_tween::prelude::ComponentTween;
 /// (applies to all tween children) or the specific tween.
 /// If the tween has a specified priority, it overrides that of its parent
 ///
-/// Possible bug causer to be aware of:
-/// Note that if you spawn two tweens with the same priority at the same time, both will be destoryed.
+/// Conflicts between tweens of equal priority are resolved by the `TweenPriorityTieBreak`
+/// set in the `TweenPrioritySettings` resource, so that exactly one of them survives
+/// per contested target, even if they were spawned in the same frame.
 ///
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
 pub struct TweenPriorityToOthersOfType(pub u32);
 
+/// Scopes priority conflicts: only tweens on the same channel compete with each other,
+/// so that, say, a "hover" scale tween and a "damage" scale tween can coexist on one entity.
+/// Tweens with no channel compete with other tweens with no channel.
+///
+/// Just like `TweenPriorityToOthersOfType`, this component can be attached to either
+/// the parent animation or the specific tween, the latter overriding the former.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub struct TweenChannel(pub &'static str);
+
+/// Sent as a message and triggered on the losing tween whenever a tween loses a priority contest,
+/// before its targets are taken away. The event propagates to the tween's animation parent.
+#[derive(Debug, Clone, Message, EntityEvent)]
+#[entity_event(propagate, auto_propagate)]
+pub struct TweenPreempted {
+    /// The entity the event is currently triggered for, first the loser and then its ancestors
+    #[event_target]
+    pub entity: Entity,
+    pub loser: Entity,
+    pub winner: Entity,
+    /// The targets taken from the loser (all of its targets if it was rejected altogether)
+    pub targets: Vec<Entity>,
+    /// The interpolator type of the loser
+    pub interpolator_type: TypeId,
+    pub preemption: TweenPreemption,
+}
+
+/// Opts the tweens of this animation parent into priority conflicts with each other,
+/// which are otherwise skipped for siblings.
+///
+/// Siblings of equal priority are ordered by the start of their `TimeSpan`,
+/// the one that starts later being considered the newer one by the `TweenPriorityTieBreak`.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct ResolveSiblingTweenPriorities;
+
+/// Decides which of two conflicting tweens with the same priority survives.
+///
+/// Tweens spawned in the same frame are ordered by their entity index,
+/// the lower index being considered the older one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TweenPriorityTieBreak {
+    /// The newer tween takes the contested targets from the older one
+    #[default]
+    NewestWins,
+    /// The older tween keeps the contested targets
+    OldestWins,
+    /// The tween with the lowest entity index keeps the contested targets
+    LowestEntityIndexWins,
+    /// The newer tween is stripped of all of its targets (and thus destroyed),
+    /// not only of the contested ones
+    RejectNewcomer,
+}
+
+/// Decides what happens to a tween (or all tweens of an animation parent) that loses
+/// a priority contest. If the tween has a specified preemption, it overrides that of its parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+pub enum TweenPreemption {
+    /// The contested targets are removed from the weaker tween for good
+    #[default]
+    Remove,
+    /// The weaker tween is suspended on the contested targets (see `SuspendedTweenTargets`)
+    /// and gets them back once the dominant tween is done or despawned.
+    /// The weaker tween keeps running in the meantime, so delta interpolators
+    /// (see `ExtraTransformTweenMakers`) resume from the target's current value.
+    Suspend,
+    /// The weaker tween is interrupted on the contested targets and pushed onto their
+    /// `TweenPriorityStack`. Once the dominant tween is done or despawned, the interrupted tween
+    /// with the highest priority regains control of the target.
+    ///
+    /// Unlike `Suspend`, the animation parent of a tween interrupted on all of its targets is paused,
+    /// so the tween resumes with its remaining duration. Note that this pauses the entire animation parent,
+    /// which suits layered states (say, hover, press and disabled) that are animated by a tween each.
+    Stack,
+}
+
+#[derive(Debug, Clone, Default, Resource)]
+pub struct TweenPrioritySettings {
+    pub tie_break: TweenPriorityTieBreak,
+    /// When set, a dominant tween only preempts tweens whose `TimeSpan` overlaps its own
+    /// (in terms of their time runners' current time), leaving alone, say,
+    /// a tween scheduled for the end of a long sequence. Off by default
+    pub preempt_only_overlapping_tweens: bool,
+}
+
 plugin_for_implementors_of_trait!(TweenPriorityHandler, Sendable);
 
 impl<T: Sendable> Plugin for TweenPriorityHandler<T> {
+    // `declare_tween_conflict` adds handlers too, explicitly adding one afterwards is fine
+    fn is_unique(&self) -> bool {
+        false
+    }
+
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        if app.is_plugin_added::<Self>() {
+            return;
+        }
+        if !app.is_plugin_added::<TweenTargetTracker<T>>() {
+            app.add_plugins(TweenTargetTracker::<T>::default());
+        }
+        app.init_resource::<TweenPrioritySettings>()
+            .add_message::<TweenPreempted>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        unwind_tween_priority_stacks::<T>,
+                        resume_suspended_tweens::<T>,
+                        handle_tween_priority_contenders::<T, T>,
+                    )
+                        .chain()
+                        .after(TweenHelpersSystemSet::TargetTracking)
+                        .in_set(TweenHelpersSystemSet::PreTargetRemoval),
+                    (
+                        listen_to_target_suspension_requests::<T>,
+                        listen_to_target_stacking_requests::<T>,
+                    )
+                        .in_set(TweenHelpersSystemSet::TargetRemoval),
+                ),
+            );
+    }
+}
+
+pub trait TweenConflictAppExt {
+    /// Makes tweens of the two interpolator types compete by priority as if they were of the same type,
+    /// for example a `Translation` tween and a tween that interpolates the entire `Transform`.
+    ///
+    /// Registers a `TweenPriorityHandler` for each type that doesn't have one yet.
+    /// Just like for conflicts within a type, each type needs a `TweenTargetRemover` as well.
+    fn declare_tween_conflict<A: Sendable, B: Sendable>(&mut self) -> &mut Self;
+}
+
+impl TweenConflictAppExt for App {
+    fn declare_tween_conflict<A: Sendable, B: Sendable>(&mut self) -> &mut Self {
+        if !self.is_plugin_added::<TweenPriorityHandler<A>>() {
+            self.add_plugins(TweenPriorityHandler::<A>::default());
+        }
+        if !self.is_plugin_added::<TweenPriorityHandler<B>>() {
+            self.add_plugins(TweenPriorityHandler::<B>::default());
+        }
+        self.add_systems(
             Update,
-            handle_tween_priority_on_spawn::<T>.in_set(TweenHelpersSystemSet::PreTargetRemoval),
-        );
+            (
+                handle_tween_priority_contenders::<A, B>,
+                handle_tween_priority_contenders::<B, A>,
+            )
+                .after(TweenHelpersSystemSet::TargetTracking)
+                .in_set(TweenHelpersSystemSet::PreTargetRemoval),
+        )
     }
 }
 
-/// The entire logic of keeping one tween over the other only runs when a new tween with priority is spawned
-/// or a new tween is spawned as a child to a parent with a priority. If a tween has no `TweenPriorityToOthersOfType`,
-/// the tween priority logic ignores it.
-fn handle_tween_priority_on_spawn<T: Sendable>(
-    mut tween_request_writer: MessageWriter<TweenRequest>,
-    tween_priorities_query: Query<&TweenPriorityToOthersOfType>,
-    all_tweens_of_type: Query<(
-        &ComponentTween<T>,
-        &ChildOf,
-        Option<&TweenPriorityToOthersOfType>,
+#[derive(SystemParam)]
+struct TweenConflictLookups<'w, 's> {
+    priorities: Query<'w, 's, &'static TweenPriorityToOthersOfType>,
+    preemptions: Query<'w, 's, &'static TweenPreemption>,
+    channels: Query<'w, 's, &'static TweenChannel>,
+    target_index: TweenTargetIndex<'w, 's>,
+    time_spans: Query<'w, 's, (&'static TimeSpan, &'static ChildOf)>,
+    time_runners: Query<'w, 's, &'static TimeRunner>,
+    sibling_resolving_parents: Query<'w, 's, (), With<ResolveSiblingTweenPriorities>>,
+    target_resolver: TweenTargetResolver<'w, 's>,
+    priority_settings: Res<'w, TweenPrioritySettings>,
+}
+
+#[derive(SystemParam)]
+struct TweenPreemptionWriters<'w, 's> {
+    tween_requests: MessageWriter<'w, TweenRequest>,
+    preemptions: MessageWriter<'w, TweenPreempted>,
+    commands: Commands<'w, 's>,
+}
+
+impl TweenPreemptionWriters<'_, '_> {
+    fn announce(&mut self, tween_preempted: TweenPreempted) {
+        self.preemptions.write(tween_preempted.clone());
+        self.commands.trigger(tween_preempted);
+    }
+}
+
+/// Tweens of a type whose priority should be (re)evaluated this frame:
+/// newborn and retargeted tweens, tweens whose `TweenPriorityToOthersOfType` was inserted or changed,
+/// and the tweens of animation parents whose `TweenPriorityToOthersOfType` was inserted or changed.
+/// Idle pooled tweens (see `IdlePooledTween`) don't contend until acquired.
+#[derive(SystemParam)]
+struct TweenPriorityContenders<'w, 's, T: Sendable> {
+    newborn_tweens: Query<'w, 's, Entity, Added<ComponentTween<T>>>,
+    reprioritized_tweens: Query<
+        'w,
+        's,
         Entity,
-    )>,
-    newborn_tweens_query: Query<
         (
-            &ComponentTween<T>,
-            &ChildOf,
-            Entity,
-            Option<&TweenPriorityToOthersOfType>,
-            Option<&Name>,
+            With<ComponentTween<T>>,
+            Changed<TweenPriorityToOthersOfType>,
         ),
-        Added<ComponentTween<T>>,
     >,
+    reprioritized_parents: Query<'w, 's, &'static Children, Changed<TweenPriorityToOthersOfType>>,
+    tweens_of_type: Query<'w, 's, (), With<ComponentTween<T>>>,
+    idle_tweens: Query<'w, 's, (), With<IdlePooledTween>>,
+    retargeted_tweens: Res<'w, RetargetedTweens<T>>,
+}
+
+impl<T: Sendable> TweenPriorityContenders<'_, '_, T> {
+    fn collect(&self) -> HashSet<Entity> {
+        let mut contenders: HashSet<Entity> = self.newborn_tweens.iter().collect();
+        contenders.extend(self.reprioritized_tweens.iter());
+        contenders.extend(self.retargeted_tweens.tweens.iter().copied());
+        for children in &self.reprioritized_parents {
+            contenders.extend(
+                children
+                    .iter()
+                    .filter(|child| self.tweens_of_type.contains(*child)),
+            );
+        }
+        contenders.retain(|contender| !self.idle_tweens.contains(*contender));
+        contenders
+    }
+}
+
+/// A tween of type `T` whose priority is being evaluated against the tweens it shares targets with
+struct TweenPriorityContender<'a, T: Sendable> {
+    entity: Entity,
+    tween: &'a ComponentTween<T>,
+    child_of: &'a ChildOf,
+    priority: &'a TweenPriorityToOthersOfType,
+}
+
+/// The entire logic of keeping one tween over the other only runs when a new tween with priority is spawned,
+/// a new tween is spawned as a child to a parent with a priority, when a tween is retargeted,
+/// or when the priority of a tween (or its parent) is inserted or changed. If a tween has no `TweenPriorityToOthersOfType`, the tween priority logic ignores it.
+///
+/// Contending tweens of type `N` are compared against the tweens of type `O` that share targets with them
+/// (looked up through `TweenTargetIndex`), `O` being the same type unless declared otherwise
+/// with `declare_tween_conflict`.
+fn handle_tween_priority_contenders<N: Sendable, O: Sendable>(
+    mut preemption_writers: TweenPreemptionWriters,
+    conflict_lookups: TweenConflictLookups,
+    contenders_of_type: TweenPriorityContenders<N>,
+    contenders_of_other_type: TweenPriorityContenders<O>,
+    tweens_of_type: Query<(
+        &ComponentTween<N>,
+        &ChildOf,
+        Option<&TweenPriorityToOthersOfType>,
+        Option<&Name>,
+    )>,
+    tweens_of_other_type: Query<(
+        &ComponentTween<O>,
+        &ChildOf,
+        Option<&TweenPriorityToOthersOfType>,
+    )>,
     logging_function: Res<TweeningLoggingFunction>,
 ) {
-    for (newborn_tween, child_of, newborn_tween_entity, maybe_tween_priority, maybe_tween_name) in
-        &newborn_tweens_query
-    {
-        let maybe_priority = if let Some(tween_priority) = maybe_tween_priority {
-            Some(tween_priority)
-        } else if let Ok(parent_priority) = tween_priorities_query.get(child_of.parent()) {
-            Some(parent_priority)
-        } else {
-            None
+    let contender_entities = contenders_of_type.collect();
+    if contender_entities.is_empty() {
+        return;
+    }
+    let other_contender_entities = contenders_of_other_type.collect();
+    for contender_tween_entity in contender_entities {
+        let Ok((contender_tween, child_of, maybe_tween_priority, maybe_tween_name)) =
+            tweens_of_type.get(contender_tween_entity)
+        else {
+            continue;
         };
+        let maybe_priority = maybe_tween_priority
+            .or_else(|| conflict_lookups.priorities.get(child_of.parent()).ok());
         if let Some(priority) = maybe_priority {
             if let Some(logger) = logging_function.0 {
                 logger(format!(
-                    "{} spawned, looking for tweens to destroy by priority",
+                    "{} spawned or changed priority, looking for tweens to destroy by priority",
                     maybe_tween_name.unwrap_or(&Name::new("A nameless tween with priority"))
                 ));
             }
             handle_tween_priority_to_others_of_type(
-                &mut tween_request_writer,
-                priority,
-                newborn_tween,
-                newborn_tween_entity,
-                child_of,
-                &all_tweens_of_type,
-                &tween_priorities_query,
+                &mut preemption_writers,
+                TweenPriorityContender {
+                    entity: contender_tween_entity,
+                    tween: contender_tween,
+                    child_of,
+                    priority,
+                },
+                &tweens_of_other_type,
+                &other_contender_entities,
+                &conflict_lookups,
             );
         }
     }
 }
 
-fn handle_tween_priority_to_others_of_type<T: Sendable>(
-    tween_request_writer: &mut MessageWriter<TweenRequest>,
-    tween_priority: &TweenPriorityToOthersOfType,
-    newborn_tween: &ComponentTween<T>,
-    newborn_tween_entity: Entity,
-    newborn_tween_child_of: &ChildOf,
-    all_tweens_of_type: &Query<(
-        &ComponentTween<T>,
+fn handle_tween_priority_to_others_of_type<N: Sendable, O: Sendable>(
+    preemption_writers: &mut TweenPreemptionWriters,
+    contender: TweenPriorityContender<N>,
+    tweens_of_other_type: &Query<(
+        &ComponentTween<O>,
         &ChildOf,
         Option<&TweenPriorityToOthersOfType>,
-        Entity,
     )>,
-    tween_priorities_query: &Query<&TweenPriorityToOthersOfType>,
+    contenders_of_other_type: &HashSet<Entity>,
+    conflict_lookups: &TweenConflictLookups,
 ) {
-    for (other_tween, child_of, maybe_other_priority, other_tween_entity) in all_tweens_of_type {
-        let sibling_tweens = newborn_tween_child_of.parent() == child_of.parent();
-        if other_tween_entity != newborn_tween_entity && !sibling_tweens {
-            if let Some(other_priority_level) = try_get_other_tween_priority(
-                maybe_other_priority,
-                child_of.parent(),
-                tween_priorities_query,
-            ) {
-                if other_priority_level <= tween_priority.0 {
-                    remove_intersecting_targets_for_weaker_tween(
-                        tween_request_writer,
-                        newborn_tween,
-                        other_tween_entity,
+    let TweenPriorityContender {
+        entity: contender_tween_entity,
+        tween: contender_tween,
+        child_of: contender_tween_child_of,
+        priority: tween_priority,
+    } = contender;
+    let priority_settings = &conflict_lookups.priority_settings;
+    let tie_break = priority_settings.tie_break;
+    let contender_tween_time_window = TweenTimeWindow::of_tween(
+        contender_tween_entity,
+        &conflict_lookups.time_spans,
+        &conflict_lookups.time_runners,
+    );
+    let contender_tween_channel = get_tween_channel(
+        contender_tween_entity,
+        contender_tween_child_of.parent(),
+        &conflict_lookups.channels,
+    );
+    for (other_tween_entity, shared_targets) in
+        tweens_sharing_targets(contender_tween_entity, contender_tween, conflict_lookups)
+    {
+        let Ok((other_tween, child_of, maybe_other_priority)) =
+            tweens_of_other_type.get(other_tween_entity)
+        else {
+            continue;
+        };
+        let sibling_tweens = contender_tween_child_of.parent() == child_of.parent();
+        if other_tween_entity == contender_tween_entity
+            || (sibling_tweens
+                && !conflict_lookups
+                    .sibling_resolving_parents
+                    .contains(child_of.parent()))
+        {
+            continue;
+        }
+        if get_tween_channel(
+            other_tween_entity,
+            child_of.parent(),
+            &conflict_lookups.channels,
+        ) != contender_tween_channel
+        {
+            continue;
+        }
+        if priority_settings.preempt_only_overlapping_tweens
+            && let Some(contender_tween_time_window) = contender_tween_time_window
+            && let Some(other_tween_time_window) = TweenTimeWindow::of_tween(
+                other_tween_entity,
+                &conflict_lookups.time_spans,
+                &conflict_lookups.time_runners,
+            )
+            && !contender_tween_time_window.overlaps(&other_tween_time_window)
+        {
+            continue;
+        }
+        let other_is_contender = contenders_of_other_type.contains(&other_tween_entity);
+        // a pair of contenders is resolved once, by the one with the lower entity
+        if other_is_contender && other_tween_entity < contender_tween_entity {
+            continue;
+        }
+        if let Some(other_priority_level) = try_get_other_tween_priority(
+            maybe_other_priority,
+            child_of.parent(),
+            &conflict_lookups.priorities,
+        ) {
+            let contender_is_newer = if sibling_tweens {
+                match compare_tween_starts(
+                    contender_tween_entity,
+                    other_tween_entity,
+                    &conflict_lookups.time_spans,
+                ) {
+                    Some(Ordering::Greater) => true,
+                    Some(Ordering::Less) => false,
+                    _ => contender_tween_entity.index() > other_tween_entity.index(),
+                }
+            } else {
+                !other_is_contender || contender_tween_entity.index() > other_tween_entity.index()
+            };
+            let tied = other_priority_level == tween_priority.0;
+            let contender_wins = if tied {
+                contender_wins_tie(
+                    tie_break,
+                    contender_is_newer,
+                    contender_tween_entity,
+                    other_tween_entity,
+                )
+            } else {
+                other_priority_level < tween_priority.0
+            };
+            let (
+                dominant_tween_entity,
+                weaker_tween_entity,
+                weaker_tween_parent,
+                weaker_tween_priority,
+                weaker_type,
+            ) = if contender_wins {
+                (
+                    contender_tween_entity,
+                    other_tween_entity,
+                    child_of.parent(),
+                    other_priority_level,
+                    TypeId::of::<O>(),
+                )
+            } else {
+                (
+                    other_tween_entity,
+                    contender_tween_entity,
+                    contender_tween_child_of.parent(),
+                    tween_priority.0,
+                    TypeId::of::<N>(),
+                )
+            };
+            let (preempted_targets, preemption) =
+                if tied && tie_break == TweenPriorityTieBreak::RejectNewcomer {
+                    let rejected_tween_targets = if contender_wins {
+                        conflict_lookups
+                            .target_resolver
+                            .resolve(other_tween_entity, other_tween)
+                    } else {
+                        conflict_lookups
+                            .target_resolver
+                            .resolve(contender_tween_entity, contender_tween)
+                    };
+                    request_target_removal(
+                        &mut preemption_writers.tween_requests,
+                        rejected_tween_targets.clone(),
+                        weaker_tween_entity,
                     );
+                    (rejected_tween_targets, TweenPreemption::Remove)
                 } else {
-                    remove_intersecting_targets_for_weaker_tween(
-                        tween_request_writer,
-                        other_tween,
-                        newborn_tween_entity,
+                    let preemption = get_tween_preemption(
+                        weaker_tween_entity,
+                        weaker_tween_parent,
+                        &conflict_lookups.preemptions,
                     );
-                }
-            }
+                    preempt_weaker_tween(
+                        &mut preemption_writers.tween_requests,
+                        shared_targets.clone(),
+                        dominant_tween_entity,
+                        weaker_tween_entity,
+                        weaker_tween_priority,
+                        preemption,
+                    );
+                    (shared_targets, preemption)
+                };
+            preemption_writers.announce(TweenPreempted {
+                entity: weaker_tween_entity,
+                loser: weaker_tween_entity,
+                winner: dominant_tween_entity,
+                targets: preempted_targets,
+                interpolator_type: weaker_type,
+                preemption,
+            });
         }
     }
 }
 
+/// Groups the targets of the tween by the other tracked tweens that target them as well
+fn tweens_sharing_targets<T: Sendable>(
+    tween_entity: Entity,
+    tween: &ComponentTween<T>,
+    conflict_lookups: &TweenConflictLookups,
+) -> HashMap<Entity, Vec<Entity>> {
+    let mut shared_targets_by_tween: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    for target in conflict_lookups
+        .target_resolver
+        .resolve(tween_entity, tween)
+    {
+        for other_tween_entity in conflict_lookups.target_index.tweens_targeting(target) {
+            shared_targets_by_tween
+                .entry(other_tween_entity)
+                .or_default()
+                .push(target);
+        }
+    }
+    shared_targets_by_tween
+}
+
+fn contender_wins_tie(
+    tie_break: TweenPriorityTieBreak,
+    contender_is_newer: bool,
+    contender_tween_entity: Entity,
+    other_tween_entity: Entity,
+) -> bool {
+    match tie_break {
+        TweenPriorityTieBreak::NewestWins => contender_is_newer,
+        TweenPriorityTieBreak::OldestWins | TweenPriorityTieBreak::RejectNewcomer => {
+            !contender_is_newer
+        }
+        TweenPriorityTieBreak::LowestEntityIndexWins => {
+            contender_tween_entity.index() < other_tween_entity.index()
+        }
+    }
+}
+
+fn compare_tween_starts(
+    tween_entity: Entity,
+    other_tween_entity: Entity,
+    time_spans: &Query<(&TimeSpan, &ChildOf)>,
+) -> Option<Ordering> {
+    let (tween_time_span, _) = time_spans.get(tween_entity).ok()?;
+    let (other_tween_time_span, _) = time_spans.get(other_tween_entity).ok()?;
+    Some(
+        tween_time_span
+            .min()
+            .duration()
+            .cmp(&other_tween_time_span.min().duration()),
+    )
+}
+
 fn try_get_other_tween_priority(
     maybe_other_tween_priority: Option<&TweenPriorityToOthersOfType>,
     other_tween_parent_entity: Entity,
@@ -138,16 +544,80 @@ fn try_get_other_tween_priority(
     }
 }
 
+fn get_tween_channel(
+    tween_entity: Entity,
+    tween_parent_entity: Entity,
+    tween_channels_query: &Query<&TweenChannel>,
+) -> Option<TweenChannel> {
+    tween_channels_query
+        .get(tween_entity)
+        .or_else(|_| tween_channels_query.get(tween_parent_entity))
+        .ok()
+        .copied()
+}
+
+fn get_tween_preemption(
+    tween_entity: Entity,
+    tween_parent_entity: Entity,
+    tween_preemptions_query: &Query<&TweenPreemption>,
+) -> TweenPreemption {
+    tween_preemptions_query
+        .get(tween_entity)
+        .or_else(|_| tween_preemptions_query.get(tween_parent_entity))
+        .copied()
+        .unwrap_or_default()
+}
+
+pub fn preempt_weaker_tween(
+    tween_request_writer: &mut MessageWriter<TweenRequest>,
+    contested_targets: Vec<Entity>,
+    dominant_tween_entity: Entity,
+    weaker_tween_entity: Entity,
+    weaker_tween_priority: u32,
+    preemption: TweenPreemption,
+) {
+    match preemption {
+        TweenPreemption::Remove => {
+            request_target_removal(tween_request_writer, contested_targets, weaker_tween_entity)
+        }
+        TweenPreemption::Suspend => request_target_suspension(
+            tween_request_writer,
+            contested_targets,
+            dominant_tween_entity,
+            weaker_tween_entity,
+        ),
+        TweenPreemption::Stack => request_target_stacking(
+            tween_request_writer,
+            contested_targets,
+            dominant_tween_entity,
+            weaker_tween_entity,
+            weaker_tween_priority,
+        ),
+    }
+}
+
 pub fn remove_intersecting_targets_for_weaker_tween<T: Sendable>(
     tween_request_writer: &mut MessageWriter<TweenRequest>,
     dominant_tween: &ComponentTween<T>,
     weaker_tween_entity: Entity,
 ) {
-    let dominant_tween_targets = get_tween_targets(dominant_tween);
-    if !dominant_tween_targets.is_empty() {
+    request_target_removal(
+        tween_request_writer,
+        get_tween_targets(dominant_tween),
+        weaker_tween_entity,
+    );
+}
+
+fn request_target_removal(
+    tween_request_writer: &mut MessageWriter<TweenRequest>,
+    targets_to_remove: Vec<Entity>,
+    tween_entity: Entity,
+) {
+    if !targets_to_remove.is_empty() {
         tween_request_writer.write(TweenRequest::RemoveEntity(RemoveTweenTargets {
-            tween_entity: weaker_tween_entity,
-            targets_to_remove: dominant_tween_targets,
+            tween_entity,
+            targets_to_remove,
+            removal_policy: None,
         }));
     }
 }
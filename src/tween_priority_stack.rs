@@ -0,0 +1,299 @@
+use crate::tween_suspension::tween_is_done;
+use crate::{prelude::*, read_single_field_variant};
+use bevy::ecs::system::SystemParam;
+use bevy_time_runner::{TimeRunner, TimeSpan};
+use tween::{ComponentTween, SkipTween, TargetComponent};
+
+/// The tweens of type `T` that were preempted on this target with `TweenPreemption::Stack`,
+/// waiting for the tween holding the target to be done.
+///
+/// Once the holding tween is done or despawned, the interrupted tween with the highest priority
+/// (the most recently interrupted one if equal) regains control of the target and becomes its holder.
+#[derive(Component, Debug)]
+pub struct TweenPriorityStack<T: Sendable> {
+    holder: Option<Entity>,
+    interrupted_tweens: Vec<InterruptedTween>,
+    _phantom: PhantomData<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterruptedTween {
+    pub tween_entity: Entity,
+    pub priority: u32,
+}
+
+impl<T: Sendable> Default for TweenPriorityStack<T> {
+    fn default() -> Self {
+        Self {
+            holder: None,
+            interrupted_tweens: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Sendable> TweenPriorityStack<T> {
+    /// The tween currently holding the target
+    pub fn holder(&self) -> Option<Entity> {
+        self.holder
+    }
+
+    pub fn interrupted_tweens(&self) -> &[InterruptedTween] {
+        &self.interrupted_tweens
+    }
+
+    /// The interrupted tween that'd regain control of the target first
+    pub fn top(&self) -> Option<&InterruptedTween> {
+        self.interrupted_tweens
+            .iter()
+            .max_by_key(|interrupted_tween| interrupted_tween.priority)
+    }
+
+    fn push(&mut self, tween_entity: Entity, priority: u32) {
+        if !self.contains(tween_entity) {
+            self.interrupted_tweens.push(InterruptedTween {
+                tween_entity,
+                priority,
+            });
+        }
+    }
+
+    fn pop(&mut self) -> Option<InterruptedTween> {
+        let top_index = self
+            .interrupted_tweens
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, interrupted_tween)| interrupted_tween.priority)
+            .map(|(index, _)| index)?;
+        Some(self.interrupted_tweens.remove(top_index))
+    }
+
+    fn contains(&self, tween_entity: Entity) -> bool {
+        self.interrupted_tweens
+            .iter()
+            .any(|interrupted_tween| interrupted_tween.tween_entity == tween_entity)
+    }
+}
+
+/// The targets a tween was interrupted on with `TweenPreemption::Stack`.
+///
+/// If a tween is interrupted on all of its targets, it's tagged with `SkipTween`
+/// and its animation parent's `TimeRunner` is paused, so that it regains control
+/// with its remaining duration (unless the dominant tween shares that parent, see `StackedTweenParents`).
+#[derive(Component, Debug, Default)]
+pub struct StackedTweenTargets {
+    pub targets: Vec<Entity>,
+    skipped_by_stacking: bool,
+    paused_animation_parent: bool,
+}
+
+/// The animation parents of tweens skipped by stacking
+#[derive(SystemParam)]
+pub struct StackedTweenParents<'w, 's> {
+    time_runners: Query<'w, 's, &'static mut TimeRunner>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+
+impl StackedTweenParents<'_, '_> {
+    /// Pauses the animation parent of the stacked tween, unless the dominant tween shares it
+    /// (see `ResolveSiblingTweenPriorities`), since that would freeze the dominant tween too.
+    /// Returns whether it was paused by this call
+    fn pause(&mut self, stacked_tween_entity: Entity, dominant_tween_entity: Entity) -> bool {
+        let Ok(child_of) = self.parents.get(stacked_tween_entity) else {
+            return false;
+        };
+        let shares_dominant_parent = self
+            .parents
+            .get(dominant_tween_entity)
+            .is_ok_and(|dominant_child_of| dominant_child_of.parent() == child_of.parent());
+        if shares_dominant_parent {
+            return false;
+        }
+        match self.time_runners.get_mut(child_of.parent()) {
+            Ok(mut time_runner) if !time_runner.paused() => {
+                time_runner.set_paused(true);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+pub fn request_target_stacking(
+    tween_request_writer: &mut MessageWriter<TweenRequest>,
+    targets_to_stack: Vec<Entity>,
+    dominant_tween_entity: Entity,
+    weaker_tween_entity: Entity,
+    weaker_tween_priority: u32,
+) {
+    if !targets_to_stack.is_empty() {
+        tween_request_writer.write(TweenRequest::StackEntity(StackTweenTargets {
+            tween_entity: weaker_tween_entity,
+            dominant_tween_entity,
+            targets_to_stack,
+            priority: weaker_tween_priority,
+        }));
+    }
+}
+
+pub fn listen_to_target_stacking_requests<T: Sendable>(
+    mut tween_request_reader: MessageReader<TweenRequest>,
+    mut tweens_of_type: Query<(
+        &mut ComponentTween<T>,
+        Option<&mut StackedTweenTargets>,
+        Option<&Name>,
+    )>,
+    mut stacks: Query<&mut TweenPriorityStack<T>>,
+    mut stacked_tween_parents: StackedTweenParents,
+    target_resolver: TweenTargetResolver,
+    logging_function: Res<TweeningLoggingFunction>,
+    mut commands: Commands,
+) {
+    let mut newly_stacked_tweens: HashMap<Entity, StackedTweenTargets> = HashMap::new();
+    let mut new_stacks: HashMap<Entity, TweenPriorityStack<T>> = HashMap::new();
+    for stack_request in read_single_field_variant!(tween_request_reader, TweenRequest::StackEntity)
+    {
+        let Ok((mut tween, maybe_stacked_targets, maybe_tween_name)) =
+            tweens_of_type.get_mut(stack_request.tween_entity)
+        else {
+            continue;
+        };
+        let interrupted_targets: Vec<Entity> = target_resolver
+            .resolve(stack_request.tween_entity, &tween)
+            .into_iter()
+            .filter(|target| stack_request.targets_to_stack.contains(target))
+            .collect();
+        for target in &stack_request.targets_to_stack {
+            let stack = match stacks.get_mut(*target) {
+                Ok(stack) => stack.into_inner(),
+                Err(_) => new_stacks.entry(*target).or_default(),
+            };
+            stack.push(stack_request.tween_entity, stack_request.priority);
+            // a dominant tween that's interrupted itself doesn't hold the target
+            if !stack.contains(stack_request.dominant_tween_entity) {
+                stack.holder = Some(stack_request.dominant_tween_entity);
+            }
+        }
+        if interrupted_targets.is_empty() {
+            continue;
+        }
+        target_resolver.make_explicit(stack_request.tween_entity, &mut tween);
+        let left_without_targets = match &mut tween.target {
+            TargetComponent::Entity(_) => true,
+            TargetComponent::Entities(tween_targets) => {
+                tween_targets.retain(|target| !interrupted_targets.contains(target));
+                tween_targets.is_empty()
+            }
+            _ => false,
+        };
+        if let Some(logger) = logging_function.0 {
+            logger(format!(
+                "stacking targets {:?} of tween: {}",
+                interrupted_targets,
+                maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
+            ));
+        }
+        let stacked_targets = match maybe_stacked_targets {
+            Some(stacked_targets) => stacked_targets.into_inner(),
+            None => newly_stacked_tweens
+                .entry(stack_request.tween_entity)
+                .or_default(),
+        };
+        stacked_targets.targets.extend(interrupted_targets);
+        if left_without_targets && !stacked_targets.skipped_by_stacking {
+            stacked_targets.skipped_by_stacking = true;
+            commands
+                .entity(stack_request.tween_entity)
+                .try_insert(SkipTween);
+            if stacked_tween_parents.pause(
+                stack_request.tween_entity,
+                stack_request.dominant_tween_entity,
+            ) {
+                stacked_targets.paused_animation_parent = true;
+            }
+        }
+    }
+    for (tween_entity, stacked_targets) in newly_stacked_tweens {
+        commands.entity(tween_entity).try_insert(stacked_targets);
+    }
+    for (target, stack) in new_stacks {
+        commands.entity(target).try_insert(stack);
+    }
+}
+
+/// Hands each target over to the top of its `TweenPriorityStack` once the tween holding it is done
+pub fn unwind_tween_priority_stacks<T: Sendable>(
+    mut stacks: Query<(&mut TweenPriorityStack<T>, Entity)>,
+    mut stacked_tweens: Query<(
+        &mut ComponentTween<T>,
+        &mut StackedTweenTargets,
+        &ChildOf,
+        Option<&Name>,
+    )>,
+    time_spans: Query<(&TimeSpan, &ChildOf)>,
+    mut time_runners: Query<&mut TimeRunner>,
+    existing_entities: Query<()>,
+    logging_function: Res<TweeningLoggingFunction>,
+    mut commands: Commands,
+) {
+    for (mut stack, target) in &mut stacks {
+        stack
+            .interrupted_tweens
+            .retain(|interrupted_tween| stacked_tweens.contains(interrupted_tween.tween_entity));
+        let holder_is_done = stack
+            .holder
+            .is_none_or(|holder| tween_is_done(holder, &time_spans, &time_runners.as_readonly()));
+        if !holder_is_done {
+            continue;
+        }
+        let Some(next_holder) = stack.pop() else {
+            commands
+                .entity(target)
+                .try_remove::<TweenPriorityStack<T>>();
+            continue;
+        };
+        stack.holder = Some(next_holder.tween_entity);
+        if !existing_entities.contains(target) {
+            continue;
+        }
+        let Ok((mut tween, mut stacked_targets, child_of, maybe_tween_name)) =
+            stacked_tweens.get_mut(next_holder.tween_entity)
+        else {
+            continue;
+        };
+        stacked_targets
+            .targets
+            .retain(|stacked_target| *stacked_target != target);
+        if let TargetComponent::Entities(tween_targets) = &mut tween.target
+            && !tween_targets.contains(&target)
+        {
+            tween_targets.push(target);
+        }
+        if let Some(logger) = logging_function.0 {
+            logger(format!(
+                "tween: {} regains control of {:?}",
+                maybe_tween_name.unwrap_or(&Name::new("(nameless)")),
+                target
+            ));
+        }
+        let has_targets_to_drive = match &tween.target {
+            TargetComponent::Entity(_) => stacked_targets.targets.is_empty(),
+            TargetComponent::Entities(tween_targets) => !tween_targets.is_empty(),
+            _ => true,
+        };
+        let mut tween_commands = commands.entity(next_holder.tween_entity);
+        if has_targets_to_drive && stacked_targets.skipped_by_stacking {
+            stacked_targets.skipped_by_stacking = false;
+            tween_commands.try_remove::<SkipTween>();
+            if stacked_targets.paused_animation_parent
+                && let Ok(mut time_runner) = time_runners.get_mut(child_of.parent())
+            {
+                time_runner.set_paused(false);
+                stacked_targets.paused_animation_parent = false;
+            }
+        }
+        if stacked_targets.targets.is_empty() {
+            tween_commands.try_remove::<StackedTweenTargets>();
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy_time_runner::{TimeRunner, TimeSpan};
+use tween::{ComponentTween, SkipTween};
+
+/// Answers what tweens of type `T` animate an entity, built on the `TweenTargetIndex`.
+/// Only tweens of types that have a `TweenTargetTracker` (or a plugin that adds it) are found
+#[derive(SystemParam)]
+pub struct TweenQuery<'w, 's, T: Sendable> {
+    target_index: TweenTargetIndex<'w, 's>,
+    tweens_of_type: Query<'w, 's, (&'static ComponentTween<T>, &'static ChildOf)>,
+    skipped_tweens: Query<'w, 's, (), With<SkipTween>>,
+    priorities: Query<'w, 's, &'static TweenPriorityToOthersOfType>,
+    time_spans: Query<'w, 's, (&'static TimeSpan, &'static ChildOf)>,
+    time_runners: Query<'w, 's, &'static TimeRunner>,
+}
+
+impl<T: Sendable> TweenQuery<'_, '_, T> {
+    /// The tweens of type `T` targeting the entity
+    pub fn tweens_targeting(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.target_index.tweens_of_type_targeting::<T>(entity)
+    }
+
+    /// Whether a tween of type `T` targets the entity and isn't skipped
+    /// (for example, by being suspended on all of its targets)
+    pub fn is_animating(&self, entity: Entity) -> bool {
+        self.tweens_targeting(entity)
+            .any(|tween_entity| !self.skipped_tweens.contains(tween_entity))
+    }
+
+    /// The animation parents of the tweens of type `T` targeting the entity, without duplicates
+    pub fn animation_parents_of(&self, entity: Entity) -> Vec<Entity> {
+        let mut animation_parents = Vec::new();
+        for tween_entity in self.tweens_targeting(entity) {
+            if let Some(animation_parent) = self.animation_parent_of(tween_entity)
+                && !animation_parents.contains(&animation_parent)
+            {
+                animation_parents.push(animation_parent);
+            }
+        }
+        animation_parents
+    }
+
+    pub fn animation_parent_of(&self, tween_entity: Entity) -> Option<Entity> {
+        self.tweens_of_type
+            .get(tween_entity)
+            .ok()
+            .map(|(_, child_of)| child_of.parent())
+    }
+
+    pub fn tween(&self, tween_entity: Entity) -> Option<&ComponentTween<T>> {
+        self.tweens_of_type
+            .get(tween_entity)
+            .ok()
+            .map(|(tween, _)| tween)
+    }
+
+    /// The priority of the tween, or that of its animation parent if it has none
+    pub fn priority_of(&self, tween_entity: Entity) -> Option<u32> {
+        if let Ok(tween_priority) = self.priorities.get(tween_entity) {
+            return Some(tween_priority.0);
+        }
+        let animation_parent = self.animation_parent_of(tween_entity)?;
+        self.priorities
+            .get(animation_parent)
+            .ok()
+            .map(|parent_priority| parent_priority.0)
+    }
+
+    /// How far along the tween is, from 0 (yet to start) to 1 (ended),
+    /// in terms of its animation parent's time runner
+    pub fn progress_of(&self, tween_entity: Entity) -> Option<f32> {
+        TweenTimeWindow::of_tween(tween_entity, &self.time_spans, &self.time_runners)
+            .map(|time_window| time_window.progress())
+    }
+}
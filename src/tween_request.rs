@@ -1,20 +1,102 @@
 use crate::prelude::*;
+use bevy::platform::collections::HashSet;
 
 #[derive(Debug, Clone, Message, Event)]
 pub enum TweenRequest {
     RemoveEntity(RemoveTweenTargets),
     RemoveTargetsFromAllTweensTargetingThem(Vec<Entity>),
+    /// Triggered (rather than sent as a message), removes the roots and all of their descendants
+    /// from all tweens targeting them, see `RemoveTargetsFromHierarchies`
+    RemoveTargetsFromHierarchies(RemoveTargetsFromHierarchies),
+    SuspendEntity(SuspendTweenTargets),
+    StackEntity(StackTweenTargets),
+    ReplaceTarget(ReplaceTweenTarget),
+    AddTargets(AddTweenTargets),
+    SetTargets(SetTweenTargets),
 }
 
 #[derive(Debug, Clone)]
 pub struct RemoveTweenTargets {
     pub tween_entity: Entity,
     pub targets_to_remove: Vec<Entity>,
+    /// Overrides the tween's `TweenTargetRemovalPolicy`
+    pub removal_policy: Option<TweenTargetRemovalPolicy>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SuspendTweenTargets {
+    pub tween_entity: Entity,
+    pub dominant_tween_entity: Entity,
+    pub targets_to_suspend: Vec<Entity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StackTweenTargets {
+    pub tween_entity: Entity,
+    pub dominant_tween_entity: Entity,
+    pub targets_to_stack: Vec<Entity>,
+    /// The priority of the stacked tween, deciding which interrupted tween regains control first
+    pub priority: u32,
+}
+
+/// Swaps one of the tween's targets for another entity, for example when a pooled entity is recycled
+#[derive(Debug, Clone)]
+pub struct ReplaceTweenTarget {
+    pub tween_entity: Entity,
+    pub target_to_replace: Entity,
+    pub replacement: Entity,
+}
+
+/// Adds targets to a running tween (a tween with a single target is turned into one with multiple targets)
+#[derive(Debug, Clone)]
+pub struct AddTweenTargets {
+    pub tween_entity: Entity,
+    pub targets_to_add: Vec<Entity>,
+}
+
+/// Replaces all of the tween's targets, for example when a pooled animation parent is acquired
+#[derive(Debug, Clone)]
+pub struct SetTweenTargets {
+    pub tween_entity: Entity,
+    pub targets: Vec<Entity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveTargetsFromHierarchies {
+    pub roots: Vec<Entity>,
+    /// Only the entities (roots included) that pass the filter are removed,
+    /// though the descendants of those that don't are still walked
+    pub filter: Option<fn(&EntityRef) -> bool>,
+}
+
+impl RemoveTargetsFromHierarchies {
+    pub fn new(roots: Vec<Entity>) -> Self {
+        Self {
+            roots,
+            filter: None,
+        }
+    }
+
+    pub fn with_filter(mut self, filter: fn(&EntityRef) -> bool) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Only removes the entities that have a `C` component
+    pub fn with_component_filter<C: Component>(self) -> Self {
+        self.with_filter(has_component::<C>)
+    }
+}
+
+fn has_component<C: Component>(entity: &EntityRef) -> bool {
+    entity.contains::<C>()
 }
 
 #[derive(Debug, Clone, Message, Event)]
 pub struct RemoveTargetsFromAllTweensOfType<T: Sendable> {
     pub targets: Vec<Entity>,
+    /// Overrides the tweens' `TweenTargetRemovalPolicy`
+    pub removal_policy: Option<TweenTargetRemovalPolicy>,
     _phantom: PhantomData<T>,
 }
 
@@ -22,15 +104,55 @@ impl<T: Sendable> RemoveTargetsFromAllTweensOfType<T> {
     pub fn new(targets: Vec<Entity>) -> Self {
         Self {
             targets,
-            _phantom: PhantomData::default(),
+            removal_policy: None,
+            _phantom: PhantomData,
         }
     }
+
+    pub fn with_removal_policy(mut self, removal_policy: TweenTargetRemovalPolicy) -> Self {
+        self.removal_policy = Some(removal_policy);
+        self
+    }
 }
 
 pub struct TweenRequestPlugin;
 
 impl Plugin for TweenRequestPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<TweenRequest>();
+        app.add_message::<TweenRequest>()
+            .add_observer(flatten_hierarchy_target_removal_request);
+    }
+}
+
+/// Turns a `RemoveTargetsFromHierarchies` request into a flat one,
+/// which is then handled by the `TweenTargetRemover` of each interpolator type
+fn flatten_hierarchy_target_removal_request(
+    trigger: On<TweenRequest>,
+    children: Query<&Children>,
+    entities: Query<EntityRef>,
+    mut commands: Commands,
+) {
+    let TweenRequest::RemoveTargetsFromHierarchies(request) = trigger.event() else {
+        return;
+    };
+    let mut visited_entities = HashSet::new();
+    let mut targets_to_remove = Vec::new();
+    for root in &request.roots {
+        for entity in std::iter::once(*root).chain(children.iter_descendants(*root)) {
+            let passes_filter = match request.filter {
+                Some(filter) => entities
+                    .get(entity)
+                    .is_ok_and(|entity_ref| filter(&entity_ref)),
+                None => true,
+            };
+            if visited_entities.insert(entity) && passes_filter {
+                targets_to_remove.push(entity);
+            }
+        }
+    }
+    if !targets_to_remove.is_empty() {
+        commands.trigger(TweenRequest::RemoveTargetsFromAllTweensTargetingThem(
+            targets_to_remove,
+        ));
     }
 }
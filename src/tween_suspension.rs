@@ -0,0 +1,180 @@
+use crate::{prelude::*, read_single_field_variant};
+use bevy_time_runner::{TimeRunner, TimeSpan};
+use tween::{ComponentTween, SkipTween, TargetComponent};
+
+/// The targets a tween was suspended on, grouped by the dominant tween that took them over.
+///
+/// Inserted by `TweenPriorityHandler` on tweens that lost a priority contest
+/// with `TweenPreemption::Suspend`. If a tween is suspended on all of its targets,
+/// it's tagged with `SkipTween` until it gets some of them back.
+#[derive(Component, Debug, Default)]
+pub struct SuspendedTweenTargets {
+    pub by_dominant_tween: HashMap<Entity, Vec<Entity>>,
+    skipped_by_suspension: bool,
+}
+
+pub fn request_target_suspension(
+    tween_request_writer: &mut MessageWriter<TweenRequest>,
+    targets_to_suspend: Vec<Entity>,
+    dominant_tween_entity: Entity,
+    weaker_tween_entity: Entity,
+) {
+    if !targets_to_suspend.is_empty() {
+        tween_request_writer.write(TweenRequest::SuspendEntity(SuspendTweenTargets {
+            tween_entity: weaker_tween_entity,
+            dominant_tween_entity,
+            targets_to_suspend,
+        }));
+    }
+}
+
+pub fn listen_to_target_suspension_requests<T: Sendable>(
+    mut tween_request_reader: MessageReader<TweenRequest>,
+    mut tweens_of_type: Query<(
+        &mut ComponentTween<T>,
+        Option<&mut SuspendedTweenTargets>,
+        Option<&Name>,
+    )>,
+    target_resolver: TweenTargetResolver,
+    logging_function: Res<TweeningLoggingFunction>,
+    mut commands: Commands,
+) {
+    let mut newly_suspended_tweens: HashMap<Entity, SuspendedTweenTargets> = HashMap::new();
+    for suspend_request in
+        read_single_field_variant!(tween_request_reader, TweenRequest::SuspendEntity)
+    {
+        let Ok((mut tween, maybe_suspension, maybe_tween_name)) =
+            tweens_of_type.get_mut(suspend_request.tween_entity)
+        else {
+            continue;
+        };
+        let suspended_targets: Vec<Entity> = target_resolver
+            .resolve(suspend_request.tween_entity, &tween)
+            .into_iter()
+            .filter(|target| suspend_request.targets_to_suspend.contains(target))
+            .collect();
+        if suspended_targets.is_empty() {
+            continue;
+        }
+        target_resolver.make_explicit(suspend_request.tween_entity, &mut tween);
+        let left_without_targets = match &mut tween.target {
+            TargetComponent::Entity(_) => true,
+            TargetComponent::Entities(tween_targets) => {
+                tween_targets.retain(|target| !suspended_targets.contains(target));
+                tween_targets.is_empty()
+            }
+            _ => false,
+        };
+        if let Some(logger) = logging_function.0 {
+            logger(format!(
+                "suspending targets {:?} of tween: {}",
+                suspended_targets,
+                maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
+            ));
+        }
+        let suspension = match maybe_suspension {
+            Some(suspension) => suspension.into_inner(),
+            None => newly_suspended_tweens
+                .entry(suspend_request.tween_entity)
+                .or_default(),
+        };
+        suspension
+            .by_dominant_tween
+            .entry(suspend_request.dominant_tween_entity)
+            .or_default()
+            .extend(suspended_targets);
+        if left_without_targets && !suspension.skipped_by_suspension {
+            suspension.skipped_by_suspension = true;
+            commands
+                .entity(suspend_request.tween_entity)
+                .try_insert(SkipTween);
+        }
+    }
+    for (tween_entity, suspension) in newly_suspended_tweens {
+        commands.entity(tween_entity).try_insert(suspension);
+    }
+}
+
+/// Gives suspended tweens their targets back once the tweens that took them over
+/// are done or despawned
+pub fn resume_suspended_tweens<T: Sendable>(
+    mut suspended_tweens: Query<(
+        &mut ComponentTween<T>,
+        &mut SuspendedTweenTargets,
+        Entity,
+        Option<&Name>,
+    )>,
+    time_spans: Query<(&TimeSpan, &ChildOf)>,
+    time_runners: Query<&TimeRunner>,
+    existing_entities: Query<()>,
+    logging_function: Res<TweeningLoggingFunction>,
+    mut commands: Commands,
+) {
+    for (mut tween, mut suspension, tween_entity, maybe_tween_name) in &mut suspended_tweens {
+        let done_dominant_tweens: Vec<Entity> = suspension
+            .by_dominant_tween
+            .keys()
+            .copied()
+            .filter(|dominant_tween| tween_is_done(*dominant_tween, &time_spans, &time_runners))
+            .collect();
+        if done_dominant_tweens.is_empty() {
+            continue;
+        }
+        let mut resumed_targets = Vec::new();
+        for dominant_tween in done_dominant_tweens {
+            if let Some(targets) = suspension.by_dominant_tween.remove(&dominant_tween) {
+                resumed_targets.extend(
+                    targets
+                        .into_iter()
+                        .filter(|target| existing_entities.contains(*target)),
+                );
+            }
+        }
+        if let TargetComponent::Entities(tween_targets) = &mut tween.target {
+            for target in &resumed_targets {
+                if !tween_targets.contains(target) {
+                    tween_targets.push(*target);
+                }
+            }
+        }
+        if let Some(logger) = logging_function.0 {
+            logger(format!(
+                "resuming targets {:?} of tween: {}",
+                resumed_targets,
+                maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
+            ));
+        }
+        let has_targets_to_drive = match &tween.target {
+            TargetComponent::Entity(_) => suspension.by_dominant_tween.is_empty(),
+            TargetComponent::Entities(tween_targets) => !tween_targets.is_empty(),
+            _ => true,
+        };
+        let mut tween_commands = commands.entity(tween_entity);
+        if suspension.by_dominant_tween.is_empty() && !has_targets_to_drive {
+            if let Some(logger) = logging_function.0 {
+                logger(format!(
+                    "destroying tween: {}",
+                    maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
+                ));
+            }
+            tween_commands.try_despawn();
+            continue;
+        }
+        if has_targets_to_drive && suspension.skipped_by_suspension {
+            suspension.skipped_by_suspension = false;
+            tween_commands.try_remove::<SkipTween>();
+        }
+        if suspension.by_dominant_tween.is_empty() {
+            tween_commands.try_remove::<SuspendedTweenTargets>();
+        }
+    }
+}
+
+pub(crate) fn tween_is_done(
+    tween_entity: Entity,
+    time_spans: &Query<(&TimeSpan, &ChildOf)>,
+    time_runners: &Query<&TimeRunner>,
+) -> bool {
+    TweenTimeWindow::of_tween(tween_entity, time_spans, time_runners)
+        .is_none_or(|time_window| time_window.has_ended())
+}
@@ -0,0 +1,102 @@
+use crate::{plugin_for_implementors_of_trait, prelude::*};
+use bevy::ecs::system::SystemParam;
+use tween::TweenPreviousValue;
+
+/// Decides what's left on a target when it's removed from a tween (or from all tweens of an animation parent).
+/// If the tween has a specified policy, it overrides that of its parent,
+/// and both are overridden by a policy specified in the removal request itself.
+///
+/// Settling a target requires a `RemovedTweenTargetSettler` for the interpolator type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+pub enum TweenTargetRemovalPolicy {
+    /// The target is left as the tween last interpolated it
+    #[default]
+    LeaveAsIs,
+    /// The target is set to the value the tween would have ended with
+    SnapToEnd,
+    /// The target is set back to the value it had when the tween started
+    RestoreStart,
+}
+
+/// Triggered by `TweenTargetRemover` when targets are removed from a tween
+/// with a policy other than `TweenTargetRemovalPolicy::LeaveAsIs`
+#[derive(Debug, Clone, Event)]
+pub struct SettleRemovedTweenTargets<T: Sendable> {
+    pub tween_entity: Entity,
+    pub targets: Vec<Entity>,
+    pub interpolator: T,
+    /// The value the tween last interpolated its targets with (0 if it hasn't applied anything yet)
+    pub last_interpolation_value: Option<f32>,
+    pub removal_policy: TweenTargetRemovalPolicy,
+}
+
+#[derive(SystemParam)]
+pub struct TweenTargetRemovalPolicies<'w, 's> {
+    policies: Query<'w, 's, &'static TweenTargetRemovalPolicy>,
+    previous_values: Query<'w, 's, &'static TweenPreviousValue>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+
+impl TweenTargetRemovalPolicies<'_, '_> {
+    pub fn policy_of(&self, tween_entity: Entity) -> TweenTargetRemovalPolicy {
+        self.policies
+            .get(tween_entity)
+            .or_else(|_| {
+                self.parents
+                    .get(tween_entity)
+                    .and_then(|child_of| self.policies.get(child_of.parent()))
+            })
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Reads `TweenPreviousValue` rather than `TweenInterpolationValue`,
+    /// since the latter keeps being sampled while the tween is skipped (and thus not applied)
+    pub fn last_interpolation_value(&self, tween_entity: Entity) -> Option<f32> {
+        self.previous_values
+            .get(tween_entity)
+            .ok()
+            .map(|previous_value| previous_value.0)
+    }
+}
+
+plugin_for_implementors_of_trait!(RemovedTweenTargetSettler, ComponentInterpolator);
+
+/// Applies `TweenTargetRemovalPolicy` to targets removed from tweens of type `I`
+impl<I: ComponentInterpolator> Plugin for RemovedTweenTargetSettler<I> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(settle_removed_tween_targets::<I>);
+    }
+}
+
+/// Interpolates the targets from the tween's last value straight to its end or start,
+/// which suits delta interpolators (see `ExtraTransformTweenMakers`) as well as absolute ones
+fn settle_removed_tween_targets<I: ComponentInterpolator>(
+    trigger: On<SettleRemovedTweenTargets<I>>,
+    mut items: Query<&mut I::Item>,
+    logging_function: Res<TweeningLoggingFunction>,
+) {
+    let (value, previous_value) = match (trigger.removal_policy, trigger.last_interpolation_value) {
+        (TweenTargetRemovalPolicy::SnapToEnd, last_value) => (1.0, last_value.unwrap_or(0.0)),
+        (TweenTargetRemovalPolicy::RestoreStart, Some(last_value)) if last_value != 0.0 => {
+            (0.0, last_value)
+        }
+        // a tween that didn't start yet (or is back at its start) has nothing to restore
+        (TweenTargetRemovalPolicy::RestoreStart, _) | (TweenTargetRemovalPolicy::LeaveAsIs, _) => {
+            return;
+        }
+    };
+    if let Some(logger) = logging_function.0 {
+        logger(format!(
+            "settling removed targets {:?} with {:?}",
+            trigger.targets, trigger.removal_policy
+        ));
+    }
+    for target in &trigger.targets {
+        if let Ok(mut item) = items.get_mut(*target) {
+            trigger
+                .interpolator
+                .interpolate(&mut item, value, previous_value);
+        }
+    }
+}
@@ -1,130 +1,177 @@
 use crate::{plugin_for_implementors_of_trait, prelude::*, read_single_field_variant};
+use bevy::ecs::entity::EntityHashMap;
+use bevy::ecs::system::SystemParam;
 use tween::{ComponentTween, TargetComponent};
 
-#[derive(Component, Deref, DerefMut)]
-pub struct TweenTargetOf(pub Vec<Entity>);
-
 plugin_for_implementors_of_trait!(TweenTargetRemover, Sendable);
 
 impl<T: Sendable> Plugin for TweenTargetRemover<T> {
+    // may already have been added by `RemoveTweensOnComponentRemoved`
+    fn is_unique(&self) -> bool {
+        false
+    }
+
     fn build(&self, app: &mut App) {
-        app.add_message::<RemoveTargetsFromAllTweensOfType<T>>()
+        if app.is_plugin_added::<Self>() {
+            return;
+        }
+        if !app.is_plugin_added::<TweenTargetTracker<T>>() {
+            app.add_plugins(TweenTargetTracker::<T>::default());
+        }
+        app.init_resource::<DespawnedTweenTargets<T>>()
+            .add_message::<RemoveTargetsFromAllTweensOfType<T>>()
             .add_observer(remove_tween_target_on_target_despawn::<T>)
             .add_observer(on_remove_targets_from_tweens_of_type::<T>)
             .add_observer(on_remove_targets_from_all_tweens_targeting_them_request::<T>)
             .add_systems(
                 Update,
                 (
-                    (
-                        track_newborn_tween_targets::<T>,
-                        track_destroyed_tween_targets::<T>,
-                    )
-                        .chain()
-                        .in_set(TweenHelpersSystemSet::PreTargetRemoval),
-                    listen_to_target_removal_requests::<T>
-                        .in_set(TweenHelpersSystemSet::TargetRemoval),
-                ),
+                    remove_despawned_targets_from_tweens::<T>,
+                    listen_to_target_removal_requests::<T>,
+                )
+                    .in_set(TweenHelpersSystemSet::TargetRemoval),
+            )
+            // targets despawned after TargetRemoval must not reach bevy_tween's apply systems
+            .add_systems(
+                PostUpdate,
+                remove_despawned_targets_from_tweens::<T>
+                    .before(TweenSystemSet::UpdateInterpolationValue),
             );
     }
 }
 
-fn track_newborn_tween_targets<T: Sendable>(
-    newborn_tweens: Query<(&ComponentTween<T>, Entity), Added<ComponentTween<T>>>,
-    mut target_of_query: Query<&mut TweenTargetOf>,
-    mut commands: Commands,
-) {
-    for (tween, tween_entity) in &newborn_tweens {
-        for target in get_tween_targets(tween) {
-            if let Ok(mut target_of) = target_of_query.get_mut(target) {
-                target_of.push(tween_entity);
-            } else {
-                commands
-                    .entity(target)
-                    .try_insert(TweenTargetOf(vec![tween_entity]));
-            }
+/// Removes entities from the targets of tweens of type `T` once their `C` component is removed,
+/// for example stopping hover tweens when `Hovered` is removed.
+/// Adds a `TweenTargetRemover` for `T` if there isn't one already.
+pub struct RemoveTweensOnComponentRemoved<C: Component, T: Sendable> {
+    _marker: PhantomData<(C, T)>,
+}
+
+impl<C: Component, T: Sendable> Default for RemoveTweensOnComponentRemoved<C, T> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
         }
     }
 }
 
-fn track_destroyed_tween_targets<T: Sendable>(
-    mut destroyed_tweens: RemovedComponents<ComponentTween<T>>,
-    tweens_of_type: Query<&ComponentTween<T>>,
-    mut target_of_query: Query<&mut TweenTargetOf>,
+impl<C: Component, T: Sendable> Plugin for RemoveTweensOnComponentRemoved<C, T> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<TweenTargetRemover<T>>() {
+            app.add_plugins(TweenTargetRemover::<T>::default());
+        }
+        app.add_observer(remove_tween_target_on_component_removal::<C, T>);
+    }
+}
+
+fn remove_tween_target_on_component_removal<C: Component, T: Sendable>(
+    trigger: On<Remove, C>,
+    logging_function: Res<TweeningLoggingFunction>,
     mut commands: Commands,
 ) {
-    for tween_entity in destroyed_tweens.read() {
-        if let Ok(tween) = tweens_of_type.get(tween_entity) {
-            for target in get_tween_targets(tween) {
-                if let Ok(mut target_of) = target_of_query.get_mut(target) {
-                    target_of.retain(|tracked_tween_entity| *tracked_tween_entity != tween_entity);
-                    if target_of.is_empty() {
-                        commands.entity(target).try_remove::<TweenTargetOf>();
-                    }
-                }
-            }
-        }
+    if let Some(logger) = logging_function.0 {
+        logger(format!(
+            "{} was removed from {:?}, removing it from tweens of type {}",
+            std::any::type_name::<C>(),
+            trigger.entity,
+            std::any::type_name::<T>()
+        ));
     }
+    commands.trigger(RemoveTargetsFromAllTweensOfType::<T>::new(vec![
+        trigger.entity,
+    ]));
 }
 
 fn on_remove_targets_from_tweens_of_type<T: Sendable>(
     trigger: On<RemoveTargetsFromAllTweensOfType<T>>,
-    mut tweens_of_type: Query<(&mut ComponentTween<T>, Entity, Option<&Name>)>,
-    logging_function: Res<TweeningLoggingFunction>,
-    mut commands: Commands,
+    mut tweens_of_type: Query<(&mut ComponentTween<T>, Option<&Name>)>,
+    target_index: TweenTargetIndex,
+    mut target_removal: TweenTargetRemoval,
 ) {
     let entities = &trigger.targets;
-    if entities.is_empty() {
-        return;
-    }
-    for (mut tween, tween_entity, maybe_tween_name) in &mut tweens_of_type {
-        remove_target_and_destroy_if_has_none(
-            entities,
-            tween_entity,
-            &mut tween,
-            maybe_tween_name,
-            &logging_function.0,
-            &mut commands,
-        );
+    for tween_entity in target_index.tweens_of_type_targeting_any::<T>(entities) {
+        if let Ok((mut tween, maybe_tween_name)) = tweens_of_type.get_mut(tween_entity) {
+            target_removal.remove_target_and_destroy_if_has_none(
+                entities,
+                tween_entity,
+                &mut tween,
+                maybe_tween_name,
+                trigger.removal_policy,
+            );
+        }
     }
 }
 
 fn on_remove_targets_from_all_tweens_targeting_them_request<T: Sendable>(
     trigger: On<TweenRequest>,
-    mut tweens_of_type: Query<(&mut ComponentTween<T>, Entity, Option<&Name>)>,
-    logging_function: Res<TweeningLoggingFunction>,
-    mut commands: Commands,
+    mut tweens_of_type: Query<(&mut ComponentTween<T>, Option<&Name>)>,
+    target_index: TweenTargetIndex,
+    mut target_removal: TweenTargetRemoval,
 ) {
     if let TweenRequest::RemoveTargetsFromAllTweensTargetingThem(entities) = trigger.event() {
-        if entities.is_empty() {
-            return;
+        for tween_entity in target_index.tweens_of_type_targeting_any::<T>(entities) {
+            if let Ok((mut tween, maybe_tween_name)) = tweens_of_type.get_mut(tween_entity) {
+                target_removal.remove_target_and_destroy_if_has_none(
+                    entities,
+                    tween_entity,
+                    &mut tween,
+                    maybe_tween_name,
+                    None,
+                );
+            }
         }
-        for (mut tween, tween_entity, maybe_tween_name) in &mut tweens_of_type {
-            remove_target_and_destroy_if_has_none(
-                &entities,
-                tween_entity,
-                &mut tween,
-                maybe_tween_name,
-                &logging_function.0,
-                &mut commands,
-            );
+    }
+}
+
+/// The despawned targets of each tween of type `T`, gathered by `remove_tween_target_on_target_despawn`
+/// and removed from their tweens in `TweenHelpersSystemSet::TargetRemoval`,
+/// then again in `PostUpdate` (before bevy_tween's systems) for targets despawned later on in the frame
+#[derive(Resource)]
+struct DespawnedTweenTargets<T: Sendable> {
+    targets_by_tween: EntityHashMap<Vec<Entity>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Sendable> Default for DespawnedTweenTargets<T> {
+    fn default() -> Self {
+        Self {
+            targets_by_tween: EntityHashMap::default(),
+            _phantom: PhantomData,
         }
     }
 }
 
+/// Only looks at the tweens linked to the departing target, see `TweenTargetIndex`
 fn remove_tween_target_on_target_despawn<T: Sendable>(
-    trigger: On<Remove, TweenTargetOf>,
-    mut query: Query<(&mut ComponentTween<T>, Option<&Name>, Entity)>,
-    logging_function: Res<TweeningLoggingFunction>,
-    mut commands: Commands,
+    trigger: On<Despawn, TweenTargetOf>,
+    target_index: TweenTargetIndex,
+    mut despawned_tween_targets: ResMut<DespawnedTweenTargets<T>>,
 ) {
-    for (mut tween, maybe_tween_name, tween_entity) in &mut query {
-        remove_target_and_destroy_if_has_none(
-            &vec![trigger.entity],
+    for tween_entity in target_index.tweens_of_type_targeting::<T>(trigger.entity) {
+        despawned_tween_targets
+            .targets_by_tween
+            .entry(tween_entity)
+            .or_default()
+            .push(trigger.entity);
+    }
+}
+
+fn remove_despawned_targets_from_tweens<T: Sendable>(
+    mut despawned_tween_targets: ResMut<DespawnedTweenTargets<T>>,
+    mut tweens_of_type: Query<(&mut ComponentTween<T>, Option<&Name>)>,
+    mut target_removal: TweenTargetRemoval,
+) {
+    for (tween_entity, despawned_targets) in despawned_tween_targets.targets_by_tween.drain() {
+        let Ok((mut tween, maybe_tween_name)) = tweens_of_type.get_mut(tween_entity) else {
+            continue;
+        };
+        // there's nothing to settle on a despawned target
+        target_removal.remove_target_and_destroy_if_has_none(
+            &despawned_targets,
             tween_entity,
             &mut tween,
             maybe_tween_name,
-            &logging_function.0,
-            &mut commands,
+            Some(TweenTargetRemovalPolicy::LeaveAsIs),
         );
     }
 }
@@ -132,65 +179,106 @@ fn remove_tween_target_on_target_despawn<T: Sendable>(
 fn listen_to_target_removal_requests<T: Sendable>(
     mut tween_request_reader: MessageReader<TweenRequest>,
     mut tweens_of_type: Query<(&mut ComponentTween<T>, Option<&Name>)>,
-    logging_function: Res<TweeningLoggingFunction>,
-    mut commands: Commands,
+    mut target_removal: TweenTargetRemoval,
 ) {
     for remove_request in
         read_single_field_variant!(tween_request_reader, TweenRequest::RemoveEntity)
     {
         if let Ok((mut tween, maybe_name)) = tweens_of_type.get_mut(remove_request.tween_entity) {
-            remove_target_and_destroy_if_has_none(
+            target_removal.remove_target_and_destroy_if_has_none(
                 &remove_request.targets_to_remove,
                 remove_request.tween_entity,
                 &mut tween,
                 maybe_name,
-                &logging_function.0,
-                &mut commands,
+                remove_request.removal_policy,
             );
         }
     }
 }
 
-fn remove_target_and_destroy_if_has_none<T: Sendable>(
-    targets_to_match: &Vec<Entity>,
-    tween_entity: Entity,
-    tween: &mut ComponentTween<T>,
-    maybe_tween_name: Option<&Name>,
-    logging_function: &Option<fn(String) -> ()>,
-    commands: &mut Commands,
-) {
-    let mut despawned_tween = false;
-    match &mut tween.target {
-        TargetComponent::Entity(tween_target) => {
-            if targets_to_match.contains(tween_target) {
-                if let Ok(mut entity_commands) = commands.get_entity(tween_entity) {
+#[derive(SystemParam)]
+struct TweenTargetRemoval<'w, 's> {
+    target_resolver: TweenTargetResolver<'w, 's>,
+    removal_policies: TweenTargetRemovalPolicies<'w, 's>,
+    logging_function: Res<'w, TweeningLoggingFunction>,
+    commands: Commands<'w, 's>,
+}
+
+impl TweenTargetRemoval<'_, '_> {
+    fn remove_target_and_destroy_if_has_none<T: Sendable>(
+        &mut self,
+        targets_to_match: &Vec<Entity>,
+        tween_entity: Entity,
+        tween: &mut ComponentTween<T>,
+        maybe_tween_name: Option<&Name>,
+        requested_removal_policy: Option<TweenTargetRemovalPolicy>,
+    ) {
+        let logging_function = self.logging_function.0;
+        let mut despawned_tween = false;
+        // a marker tween losing its target is handled like any other single target tween
+        if let TargetComponent::Marker { .. } = tween.target
+            && self
+                .target_resolver
+                .resolve(tween_entity, tween)
+                .iter()
+                .any(|target| targets_to_match.contains(target))
+        {
+            self.target_resolver.make_explicit(tween_entity, tween);
+        }
+        let mut removed_targets = Vec::new();
+        match &mut tween.target {
+            TargetComponent::Entity(tween_target) if targets_to_match.contains(tween_target) => {
+                removed_targets.push(*tween_target);
+                if let Ok(mut entity_commands) = self.commands.get_entity(tween_entity) {
                     entity_commands.try_despawn();
                     despawned_tween = true;
                 }
             }
-        }
-        TargetComponent::Entities(tween_targets) => {
-            tween_targets.retain(|target| !targets_to_match.contains(target));
-            if let Some(logger) = logging_function {
-                logger(format!(
-                    "removing targets {:?} from tween: {}",
-                    targets_to_match,
-                    maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
-                ));
-            }
-            if tween_targets.is_empty() {
-                if let Ok(mut entity_commands) = commands.get_entity(tween_entity) {
+            TargetComponent::Entities(tween_targets) => {
+                let targets_count_before_removal = tween_targets.len();
+                tween_targets.retain(|target| {
+                    let matched = targets_to_match.contains(target);
+                    if matched {
+                        removed_targets.push(*target);
+                    }
+                    !matched
+                });
+                if let Some(logger) = logging_function {
+                    logger(format!(
+                        "removing targets {:?} from tween: {}",
+                        targets_to_match,
+                        maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
+                    ));
+                }
+                // a suspended tween might be left with no targets, see `SuspendedTweenTargets`
+                if tween_targets.is_empty()
+                    && tween_targets.len() != targets_count_before_removal
+                    && let Ok(mut entity_commands) = self.commands.get_entity(tween_entity)
+                {
                     entity_commands.try_despawn();
                     despawned_tween = true;
                 }
             }
+            _ => {}
+        }
+        let removal_policy = requested_removal_policy
+            .unwrap_or_else(|| self.removal_policies.policy_of(tween_entity));
+        if !removed_targets.is_empty() && removal_policy != TweenTargetRemovalPolicy::LeaveAsIs {
+            self.commands.trigger(SettleRemovedTweenTargets {
+                tween_entity,
+                targets: removed_targets,
+                interpolator: tween.interpolator.clone(),
+                last_interpolation_value: self
+                    .removal_policies
+                    .last_interpolation_value(tween_entity),
+                removal_policy,
+            });
+        }
+        if despawned_tween && let Some(logger) = logging_function {
+            logger(format!(
+                "destroying tween: {}",
+                maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
+            ));
         }
-        _ => {}
-    }
-    if despawned_tween && let Some(logger) = logging_function {
-        logger(format!(
-            "destroying tween: {}",
-            maybe_tween_name.unwrap_or(&Name::new("(nameless)"))
-        ));
     }
 }
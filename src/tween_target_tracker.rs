@@ -0,0 +1,277 @@
+use crate::{plugin_for_implementors_of_trait, prelude::*};
+use bevy::ecs::system::SystemParam;
+use std::any::TypeId;
+use tween::{ComponentTween, TargetComponent};
+
+/// Lives on a link entity tying a tween to one of its targets (see `LinkedTweenTarget`).
+/// Link entities are spawned by `TweenTargetTracker` and despawned along with either the tween or the target
+#[derive(Component, Debug)]
+#[relationship(relationship_target = TweenTargetLinks)]
+pub struct LinkedTween(pub Entity);
+
+/// Lives on a link entity tying a target to one of the tweens targeting it (see `LinkedTween`)
+#[derive(Component, Debug)]
+#[relationship(relationship_target = TweenTargetOf)]
+pub struct LinkedTweenTarget(pub Entity);
+
+/// Lives on a link entity, naming the interpolator type of the linked tween
+/// so that lookups can skip tweens of other types
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkedTweenType {
+    pub type_id: TypeId,
+    pub type_name: &'static str,
+}
+
+impl LinkedTweenType {
+    pub fn of<T: Sendable>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+}
+
+/// Lists the links to the targets of this tween
+#[derive(Component, Debug)]
+#[relationship_target(relationship = LinkedTween, linked_spawn)]
+pub struct TweenTargetLinks(Vec<Entity>);
+
+/// Lists the links to the tweens (of all tracked types) targeting this entity,
+/// use `TweenTargetIndex` to get the tweens themselves, optionally of a given type
+#[derive(Component, Debug)]
+#[relationship_target(relationship = LinkedTweenTarget, linked_spawn)]
+pub struct TweenTargetOf(Vec<Entity>);
+
+/// Navigates the links between tweens and their targets in both directions
+#[derive(SystemParam)]
+pub struct TweenTargetIndex<'w, 's> {
+    target_of: Query<'w, 's, &'static TweenTargetOf>,
+    tween_links: Query<'w, 's, &'static TweenTargetLinks>,
+    links: Query<
+        'w,
+        's,
+        (
+            &'static LinkedTween,
+            &'static LinkedTweenTarget,
+            &'static LinkedTweenType,
+        ),
+    >,
+}
+
+impl TweenTargetIndex<'_, '_> {
+    /// The tweens (of all tracked types) targeting the entity
+    pub fn tweens_targeting(&self, target: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.links_to(target)
+            .map(|(linked_tween, _, _)| linked_tween.0)
+    }
+
+    /// The tweens of type `T` targeting the entity
+    pub fn tweens_of_type_targeting<T: Sendable>(
+        &self,
+        target: Entity,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        self.links_to(target)
+            .filter(|(_, _, tween_type)| tween_type.type_id == TypeId::of::<T>())
+            .map(|(linked_tween, _, _)| linked_tween.0)
+    }
+
+    /// The tweens of type `T` targeting any of the entities, without duplicates
+    pub fn tweens_of_type_targeting_any<T: Sendable>(&self, targets: &[Entity]) -> Vec<Entity> {
+        let mut tweens = Vec::new();
+        for target in targets {
+            for tween_entity in self.tweens_of_type_targeting::<T>(*target) {
+                if !tweens.contains(&tween_entity) {
+                    tweens.push(tween_entity);
+                }
+            }
+        }
+        tweens
+    }
+
+    /// The interpolator types of the tweens (of all tracked types) targeting the entity, without duplicates
+    pub fn interpolator_types_animating(&self, target: Entity) -> Vec<LinkedTweenType> {
+        let mut tween_types = Vec::new();
+        for (_, _, tween_type) in self.links_to(target) {
+            if !tween_types.contains(tween_type) {
+                tween_types.push(*tween_type);
+            }
+        }
+        tween_types
+    }
+
+    /// The tracked targets of the tween
+    pub fn targets_of(&self, tween: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.links_of(tween).map(|(_, target)| target)
+    }
+
+    /// The links of the tween, each paired with the target it leads to
+    pub fn links_of(&self, tween: Entity) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.tween_links
+            .get(tween)
+            .into_iter()
+            .flat_map(|tween_links| tween_links.iter())
+            .filter_map(|link| {
+                self.links
+                    .get(link)
+                    .ok()
+                    .map(|(_, linked_target, _)| (link, linked_target.0))
+            })
+    }
+
+    fn links_to(
+        &self,
+        target: Entity,
+    ) -> impl Iterator<Item = (&LinkedTween, &LinkedTweenTarget, &LinkedTweenType)> + '_ {
+        self.target_of
+            .get(target)
+            .into_iter()
+            .flat_map(|target_of| target_of.iter())
+            .filter_map(|link| self.links.get(link).ok())
+    }
+
+    pub fn is_targeting(&self, tween: Entity, target: Entity) -> bool {
+        self.targets_of(tween)
+            .any(|tracked_target| tracked_target == target)
+    }
+}
+
+/// The tweens of type `T` that were retargeted (see `TweenRequest::ReplaceTarget`, `TweenRequest::AddTargets` and `TweenRequest::SetTargets`)
+/// this frame, which `TweenPriorityHandler` re-evaluates
+#[derive(Resource, Debug)]
+pub struct RetargetedTweens<T: Sendable> {
+    pub tweens: Vec<Entity>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Sendable> Default for RetargetedTweens<T> {
+    fn default() -> Self {
+        Self {
+            tweens: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+plugin_for_implementors_of_trait!(TweenTargetTracker, Sendable);
+
+/// Links tweens of type `T` to their targets, see `TweenTargetIndex`.
+/// Added by both `TweenTargetRemover` and `TweenPriorityHandler`, so there's no need to add it yourself.
+impl<T: Sendable> Plugin for TweenTargetTracker<T> {
+    // plugins relying on the tracker add it for you, so adding it again must be a no-op
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut App) {
+        if app.is_plugin_added::<Self>() {
+            return;
+        }
+        app.init_resource::<RetargetedTweens<T>>()
+            .add_message::<TweenRequest>()
+            .add_systems(
+                Update,
+                (
+                    listen_to_retargeting_requests::<T>,
+                    track_tween_targets::<T>,
+                )
+                    .chain()
+                    .in_set(TweenHelpersSystemSet::TargetTracking),
+            );
+    }
+}
+
+fn listen_to_retargeting_requests<T: Sendable>(
+    mut tween_request_reader: MessageReader<TweenRequest>,
+    mut tweens_of_type: Query<(&mut ComponentTween<T>, Option<&Name>)>,
+    mut retargeted_tweens: ResMut<RetargetedTweens<T>>,
+    target_resolver: TweenTargetResolver,
+    logging_function: Res<TweeningLoggingFunction>,
+) {
+    retargeted_tweens.tweens.clear();
+    for tween_request in tween_request_reader.read() {
+        let (tween_entity, targets_to_remove, targets_to_add) = match tween_request {
+            TweenRequest::ReplaceTarget(replace_request) => (
+                replace_request.tween_entity,
+                Some(vec![replace_request.target_to_replace]),
+                vec![replace_request.replacement],
+            ),
+            TweenRequest::AddTargets(add_request) => (
+                add_request.tween_entity,
+                Some(vec![]),
+                add_request.targets_to_add.clone(),
+            ),
+            // None stands for removing all of the tween's targets
+            TweenRequest::SetTargets(set_request) => {
+                (set_request.tween_entity, None, set_request.targets.clone())
+            }
+            _ => continue,
+        };
+        let Ok((mut tween, maybe_tween_name)) = tweens_of_type.get_mut(tween_entity) else {
+            continue;
+        };
+        target_resolver.make_explicit(tween_entity, &mut tween);
+        let mut tween_targets = get_tween_targets(&tween);
+        match targets_to_remove {
+            Some(targets_to_remove) if !targets_to_remove.is_empty() => {
+                let targets_count_before_removal = tween_targets.len();
+                tween_targets.retain(|target| !targets_to_remove.contains(target));
+                if tween_targets.len() == targets_count_before_removal {
+                    continue;
+                }
+            }
+            Some(_) => {}
+            None => tween_targets.clear(),
+        }
+        for target in targets_to_add {
+            if !tween_targets.contains(&target) {
+                tween_targets.push(target);
+            }
+        }
+        if let Some(logger) = logging_function.0 {
+            logger(format!(
+                "retargeting tween: {} to {:?}",
+                maybe_tween_name.unwrap_or(&Name::new("(nameless)")),
+                tween_targets
+            ));
+        }
+        let keeps_single_target =
+            matches!(tween.target, TargetComponent::Entity(_)) && tween_targets.len() == 1;
+        tween.target = if keeps_single_target {
+            TargetComponent::Entity(tween_targets[0])
+        } else {
+            TargetComponent::Entities(tween_targets)
+        };
+        retargeted_tweens.tweens.push(tween_entity);
+    }
+}
+
+/// Links newborn tweens to their targets, and keeps the links of tweens whose targets changed
+/// (for example by being retargeted or removed) in sync. The tweens of idle pooled animation parents aren't linked,
+/// see `IdlePooledTween`
+fn track_tween_targets<T: Sendable>(
+    changed_tweens: Query<
+        (&ComponentTween<T>, Entity),
+        (Changed<ComponentTween<T>>, Without<IdlePooledTween>),
+    >,
+    target_resolver: TweenTargetResolver,
+    target_index: TweenTargetIndex,
+    mut commands: Commands,
+) {
+    for (tween, tween_entity) in &changed_tweens {
+        let tween_targets = target_resolver.resolve(tween_entity, tween);
+        for (link, tracked_target) in target_index.links_of(tween_entity) {
+            if !tween_targets.contains(&tracked_target) {
+                commands.entity(link).try_despawn();
+            }
+        }
+        for target in tween_targets {
+            if !target_index.is_targeting(tween_entity, target) {
+                commands.spawn((
+                    LinkedTween(tween_entity),
+                    LinkedTweenTarget(target),
+                    LinkedTweenType::of::<T>(),
+                ));
+            }
+        }
+    }
+}
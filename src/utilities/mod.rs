@@ -1,2 +1,3 @@
 pub mod system_sets;
-pub mod trait_unions;
\ No newline at end of file
+pub mod trait_unions;
+pub mod tween_time_window;
\ No newline at end of file
@@ -2,6 +2,8 @@ use crate::prelude::*;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum TweenHelpersSystemSet {
+    /// Runs within `PreTargetRemoval`, links newborn tweens to their targets (see `TweenTargetIndex`)
+    TargetTracking,
     PreTargetRemoval,
     TargetRemoval,
 }
@@ -17,7 +19,8 @@ impl Plugin for BevyTweenHelpersSystemSetsPlugin {
                  TweenHelpersSystemSet::PreTargetRemoval,
                  TweenHelpersSystemSet::TargetRemoval,
              )
-                 .chain(),),
+                 .chain(),
+             TweenHelpersSystemSet::TargetTracking.in_set(TweenHelpersSystemSet::PreTargetRemoval)),
         );
     }
 }
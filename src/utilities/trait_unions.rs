@@ -1,5 +1,9 @@
 use std::fmt::Debug;
 
+use bevy::ecs::component::{Component, Mutable};
+use bevy_tween::prelude::Interpolator;
+
 use crate::trait_union;
 
 trait_union!(Sendable, Clone + Send + Sync + 'static + Debug);
+trait_union!(ComponentInterpolator, Interpolator<Item: Component<Mutability = Mutable>> + Sendable);
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use bevy_time_runner::{TimeRunner, TimeSpan};
+
+/// The time in which a tween is active, in seconds relative to its time runner's current time
+/// (negative values are in the past), within the time runner's current cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TweenTimeWindow {
+    pub start: f32,
+    pub end: f32,
+    /// Whether the time runner has repeats left, in which case the tween comes back in its next cycles
+    pub repeats: bool,
+}
+
+impl TweenTimeWindow {
+    pub fn new(time_span: &TimeSpan, time_runner: &TimeRunner) -> Self {
+        let now = time_runner.elasped().now();
+        Self {
+            start: time_span.min().duration().as_secs_f32() - now,
+            end: time_span.max().duration().as_secs_f32() - now,
+            repeats: time_runner
+                .repeat()
+                .is_some_and(|(repeat, _)| !repeat.exhausted()),
+        }
+    }
+
+    pub fn of_tween(
+        tween_entity: Entity,
+        time_spans: &Query<(&TimeSpan, &ChildOf)>,
+        time_runners: &Query<&TimeRunner>,
+    ) -> Option<Self> {
+        let (time_span, child_of) = time_spans.get(tween_entity).ok()?;
+        let time_runner = time_runners.get(child_of.parent()).ok()?;
+        Some(Self::new(time_span, time_runner))
+    }
+
+    /// Tweens of repeating time runners never end, ending in the current cycle notwithstanding
+    pub fn has_ended(&self) -> bool {
+        !self.repeats && self.end <= 0.0
+    }
+
+    /// How far along the tween is in the current cycle, from 0 (yet to start) to 1 (ended)
+    pub fn progress(&self) -> f32 {
+        let duration = self.end - self.start;
+        if duration <= 0.0 {
+            return if self.end <= 0.0 { 1.0 } else { 0.0 };
+        }
+        (-self.start / duration).clamp(0.0, 1.0)
+    }
+
+    /// Whether both tweens are active at the same time from now on,
+    /// a repeating tween being considered active for as long as the other one didn't end
+    pub fn overlaps(&self, other: &Self) -> bool {
+        if self.repeats || other.repeats {
+            return !self.has_ended() && !other.has_ended();
+        }
+        let overlap_start = self.start.max(other.start).max(0.0);
+        let overlap_end = self.end.min(other.end);
+        overlap_start < overlap_end
+    }
+}
@@ -0,0 +1,95 @@
+use bevy_tween::combinator::{AnimationBuilderExt, tween};
+use bevy_tween::interpolation::EaseKind;
+use bevy_tween::prelude::{Interpolator, IntoTarget};
+use bevy_tween_helpers::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn test_is_animating_follows_tweens() {
+    let mut app = App::new();
+
+    app.init_resource::<AnimatingChanges>();
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        AnimatingTargetTagger::<MePolator>::default(),
+        AnimatingTargetTagger::<OtherPolator>::default(),
+    ));
+    app.add_observer(
+        |trigger: On<TargetStartedAnimating<MePolator>>,
+         mut animating_changes: ResMut<AnimatingChanges>| {
+            animating_changes.started.push(trigger.entity);
+        },
+    );
+    app.add_observer(
+        |trigger: On<TargetStoppedAnimating<MePolator>>,
+         mut animating_changes: ResMut<AnimatingChanges>| {
+            animating_changes.stopped.push(trigger.entity);
+        },
+    );
+    let target = app.world_mut().spawn(()).id();
+    let mut state = target.into_target().state(());
+    app.world_mut()
+        .commands()
+        .spawn(())
+        .animation()
+        .insert(tween(
+            Duration::from_secs(5),
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+        ));
+    app.update();
+    app.update();
+
+    let tagged_while_animating = app.world().get::<IsAnimating<MePolator>>(target).is_some();
+    let tagged_by_other_type = app
+        .world()
+        .get::<IsAnimating<OtherPolator>>(target)
+        .is_some();
+
+    app.world_mut()
+        .trigger(RemoveTargetsFromAllTweensOfType::<MePolator>::new(vec![
+            target,
+        ]));
+    app.update();
+    app.update();
+
+    let tagged_after_removal = app.world().get::<IsAnimating<MePolator>>(target).is_some();
+    let animating_changes = app.world().resource::<AnimatingChanges>();
+
+    assert!(tagged_while_animating);
+    assert!(!tagged_by_other_type);
+    assert!(!tagged_after_removal);
+    assert_eq!(animating_changes.started, vec![target]);
+    assert_eq!(animating_changes.stopped, vec![target]);
+}
+
+fn log(log_me: String) {
+    println!("{}", log_me);
+}
+
+#[derive(Resource, Default)]
+struct AnimatingChanges {
+    started: Vec<Entity>,
+    stopped: Vec<Entity>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MePolator;
+
+impl Interpolator for MePolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
+
+#[derive(Clone, Copy, Debug)]
+struct OtherPolator;
+
+impl Interpolator for OtherPolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
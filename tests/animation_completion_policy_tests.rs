@@ -0,0 +1,122 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy_tween::combinator::{AnimationBuilderExt, TransformTargetStateExt, tween};
+use bevy_tween::interpolation::EaseKind;
+use bevy_tween::prelude::{DefaultTweenPlugins, Interpolator, IntoTarget};
+use bevy_tween_helpers::prelude::bevy_time_runner::TimeRunner;
+use bevy_tween_helpers::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn test_completion_policies() {
+    let mut app = App::new();
+
+    app.init_resource::<Time>().add_plugins((
+        DefaultTweenPlugins::<()>::in_schedule(PostUpdate.intern()),
+        AnimationParentDestroyerPlugin {
+            default_completion_policy: OnAnimationComplete::Keep,
+        },
+    ));
+    app.init_resource::<ReturnedToPool>();
+    app.add_observer(
+        |trigger: On<ReturnAnimationParentToPool>, mut returned_to_pool: ResMut<ReturnedToPool>| {
+            returned_to_pool.0.push(trigger.entity);
+        },
+    );
+    let kept_by_default = spawn_animation_parent(&mut app, None);
+    let despawned = spawn_animation_parent(&mut app, Some(OnAnimationComplete::Despawn));
+    let reset = spawn_animation_parent(&mut app, Some(OnAnimationComplete::ResetAndPause));
+    let pooled = spawn_animation_parent(&mut app, Some(OnAnimationComplete::ReturnToPool));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(TWEEN_DURATION * 2);
+    app.update();
+    app.update();
+
+    let reset_time_runner = app.world().get::<TimeRunner>(reset).unwrap();
+    let reset_is_paused = reset_time_runner.paused();
+    let reset_elapsed = reset_time_runner.elasped().now();
+
+    assert!(app.world().get_entity(kept_by_default).is_ok());
+    assert!(app.world().get_entity(despawned).is_err());
+    assert!(reset_is_paused);
+    assert_eq!(reset_elapsed, 0.0);
+    assert!(app.world().get::<TimeRunner>(pooled).unwrap().paused());
+    assert_eq!(app.world().resource::<ReturnedToPool>().0, vec![pooled]);
+}
+
+#[test]
+fn test_reset_delta_tweens_replay_forwards() {
+    let mut app = App::new();
+
+    app.init_resource::<Time>().add_plugins((
+        DefaultTweenPlugins::<()>::in_schedule(PostUpdate.intern()),
+        AnimationParentDestroyerPlugin {
+            default_completion_policy: OnAnimationComplete::ResetAndPause,
+        },
+    ));
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let mut transform_state = target.into_target().transform_state(Transform::default());
+    let animation_parent = app
+        .world_mut()
+        .commands()
+        .spawn(())
+        .animation()
+        .insert(tween(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            transform_state.translation_delta_to(Vec3::X),
+        ))
+        .id();
+    // the time advanced by is also advanced by in every update after it
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(TWEEN_DURATION / 2);
+    let mut translations = Vec::new();
+    for _ in 0..3 {
+        app.update();
+        translations.push(app.world().get::<Transform>(target).unwrap().translation.x);
+    }
+    app.world_mut()
+        .get_mut::<TimeRunner>(animation_parent)
+        .unwrap()
+        .set_paused(false);
+    for _ in 0..2 {
+        app.update();
+        translations.push(app.world().get::<Transform>(target).unwrap().translation.x);
+    }
+
+    assert_eq!(translations, vec![0.5, 1.0, 1.0, 1.5, 2.0]);
+}
+
+fn spawn_animation_parent(app: &mut App, completion_policy: Option<OnAnimationComplete>) -> Entity {
+    let target = app.world_mut().spawn(()).id();
+    let mut state = target.into_target().state(());
+    let mut commands = app.world_mut().commands();
+    let mut animation_parent = commands.spawn(());
+    if let Some(completion_policy) = completion_policy {
+        animation_parent.insert(completion_policy);
+    }
+    let animation_parent_entity = animation_parent.id();
+    animation_parent.animation().insert(tween(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+    ));
+    animation_parent_entity
+}
+
+const TWEEN_DURATION: Duration = Duration::from_secs(1);
+
+#[derive(Resource, Default)]
+struct ReturnedToPool(Vec<Entity>);
+
+#[derive(Clone, Copy, Debug)]
+struct MePolator;
+
+impl Interpolator for MePolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
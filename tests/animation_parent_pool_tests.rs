@@ -0,0 +1,273 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_tween::combinator::{AnimationBuilderExt, TransformTargetStateExt, tween};
+use bevy_tween::interpolate::Translation;
+use bevy_tween::interpolation::EaseKind;
+use bevy_tween::prelude::{DefaultTweenPlugins, Interpolator, IntoTarget};
+use bevy_tween::tween::{ComponentTween, SkipTween, TargetComponent};
+use bevy_tween_helpers::prelude::bevy_time_runner::TimeRunner;
+use bevy_tween_helpers::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn test_completed_pooled_parents_are_recycled() {
+    let mut app = make_app();
+    let first_target = app.world_mut().spawn(()).id();
+    let recycled_parent = spawn_pooled_animation_parent(&mut app, first_target);
+    let overflowing_parent = spawn_pooled_animation_parent(&mut app, first_target);
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(TWEEN_DURATION * 2);
+    app.update();
+    app.update();
+
+    let idle_count = app
+        .world()
+        .resource::<AnimationParentPool>()
+        .idle_count(POOL);
+    let overflowing_parent_despawned = app.world().get_entity(overflowing_parent).is_err();
+
+    let second_target = app.world_mut().spawn(()).id();
+    let acquired_parent = app
+        .world_mut()
+        .run_system_once(move |mut recycler: AnimationParentRecycler| {
+            recycler.acquire(POOL, second_target)
+        })
+        .unwrap();
+    app.update();
+
+    let acquired_is_playing = !app
+        .world()
+        .get::<TimeRunner>(recycled_parent)
+        .unwrap()
+        .paused();
+    let tween_target = app
+        .world_mut()
+        .query::<&ComponentTween<MePolator>>()
+        .single(app.world())
+        .unwrap()
+        .target
+        .clone();
+
+    assert_eq!(idle_count, 1);
+    assert!(overflowing_parent_despawned);
+    assert_eq!(acquired_parent, Some(recycled_parent));
+    assert!(acquired_is_playing);
+    assert!(matches!(tween_target, TargetComponent::Entity(target) if target == second_target));
+    assert_eq!(
+        app.world()
+            .resource::<AnimationParentPool>()
+            .idle_count(POOL),
+        0
+    );
+}
+
+#[test]
+fn test_idle_pooled_parents_outlive_their_former_targets() {
+    let mut app = make_app();
+    app.add_plugins((
+        AnimatingTargetTagger::<MePolator>::default(),
+        TweenTargetRemover::<MePolator>::default(),
+        AnimationParentDestroyerGenericPlugin::<MePolator>::default(),
+    ));
+    let first_target = app.world_mut().spawn(()).id();
+    let pooled_parent = spawn_pooled_animation_parent(&mut app, first_target);
+    app.update();
+    let first_target_animating_while_playing = app
+        .world()
+        .get::<IsAnimating<MePolator>>(first_target)
+        .is_some();
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(TWEEN_DURATION * 2);
+    app.update();
+    app.update();
+    // the parent returns to its pool in PostUpdate, targets are untagged in the next Update
+    app.update();
+    let first_target_animating_while_idle = app
+        .world()
+        .get::<IsAnimating<MePolator>>(first_target)
+        .is_some();
+
+    app.world_mut().entity_mut(first_target).despawn();
+    app.update();
+    let idle_count_after_target_despawn = app
+        .world()
+        .resource::<AnimationParentPool>()
+        .idle_count(POOL);
+
+    let second_target = app.world_mut().spawn(()).id();
+    let acquired_parent = app
+        .world_mut()
+        .run_system_once(move |mut recycler: AnimationParentRecycler| {
+            recycler.acquire(POOL, second_target)
+        })
+        .unwrap();
+    app.update();
+
+    let tween_entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ComponentTween<MePolator>>>()
+        .single(app.world())
+        .unwrap();
+    let tween_target = app
+        .world()
+        .get::<ComponentTween<MePolator>>(tween_entity)
+        .unwrap()
+        .target
+        .clone();
+
+    assert!(first_target_animating_while_playing);
+    assert!(!first_target_animating_while_idle);
+    assert_eq!(idle_count_after_target_despawn, 1);
+    assert_eq!(acquired_parent, Some(pooled_parent));
+    assert!(matches!(tween_target, TargetComponent::Entity(target) if target == second_target));
+    assert!(app.world().get::<IdlePooledTween>(tween_entity).is_none());
+    assert!(app.world().get::<SkipTween>(tween_entity).is_none());
+    assert!(
+        app.world()
+            .get::<IsAnimating<MePolator>>(second_target)
+            .is_some()
+    );
+}
+
+#[test]
+fn test_recycled_delta_tweens_animate_their_new_target_from_the_start() {
+    let mut app = make_app();
+    app.add_plugins(TweenTargetTracker::<Translation>::default());
+    let first_target = app.world_mut().spawn(Transform::default()).id();
+    let mut transform_state = first_target
+        .into_target()
+        .transform_state(Transform::default());
+    app.world_mut()
+        .commands()
+        .spawn(PooledAnimationParent(POOL))
+        .animation()
+        .insert(tween(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            transform_state.translation_delta_to(Vec3::X),
+        ));
+    // the time advanced by is also advanced by in every update after it
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(TWEEN_DURATION / 2);
+    app.update();
+    app.update();
+    app.update();
+
+    let second_target = app.world_mut().spawn(Transform::default()).id();
+    app.world_mut()
+        .run_system_once(move |mut recycler: AnimationParentRecycler| {
+            recycler.acquire(POOL, second_target)
+        })
+        .unwrap();
+    let mut second_target_translations = Vec::new();
+    for _ in 0..2 {
+        app.update();
+        second_target_translations.push(
+            app.world()
+                .get::<Transform>(second_target)
+                .unwrap()
+                .translation
+                .x,
+        );
+    }
+
+    assert_eq!(
+        app.world()
+            .get::<Transform>(first_target)
+            .unwrap()
+            .translation
+            .x,
+        1.0
+    );
+    assert_eq!(second_target_translations, vec![0.5, 1.0]);
+}
+
+#[test]
+fn test_pool_prewarming() {
+    let mut app = make_app();
+    let placeholder_target = app.world_mut().spawn(()).id();
+
+    app.world_mut()
+        .run_system_once(move |mut recycler: AnimationParentRecycler| {
+            recycler.prewarm(POOL, 3, |commands| {
+                let mut state = placeholder_target.into_target().state(());
+                let mut animation_parent = commands.spawn(());
+                let animation_parent_entity = animation_parent.id();
+                animation_parent.animation().insert(tween(
+                    TWEEN_DURATION,
+                    EaseKind::Linear,
+                    state.with(move |_state| MePolator),
+                ));
+                animation_parent_entity
+            });
+        })
+        .unwrap();
+    app.update();
+
+    let paused_parents = app
+        .world_mut()
+        .query::<(&TimeRunner, &PooledAnimationParent)>()
+        .iter(app.world())
+        .filter(|(time_runner, _)| time_runner.paused())
+        .count();
+
+    assert_eq!(
+        app.world()
+            .resource::<AnimationParentPool>()
+            .idle_count(POOL),
+        POOL_CAPACITY
+    );
+    assert_eq!(paused_parents, POOL_CAPACITY);
+}
+
+fn make_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>().add_plugins((
+        DefaultTweenPlugins::<()>::in_schedule(PostUpdate.intern()),
+        BevyTweenHelpersPlugin {
+            logging_function: Some(log),
+            ..default()
+        },
+        AnimationParentPoolPlugin {
+            capacity: POOL_CAPACITY,
+        },
+        TweenTargetTracker::<MePolator>::default(),
+    ));
+    app
+}
+
+fn spawn_pooled_animation_parent(app: &mut App, target: Entity) -> Entity {
+    let mut state = target.into_target().state(());
+    let mut commands = app.world_mut().commands();
+    let mut animation_parent = commands.spawn(PooledAnimationParent(POOL));
+    let animation_parent_entity = animation_parent.id();
+    animation_parent.animation().insert(tween(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+    ));
+    animation_parent_entity
+}
+
+fn log(log_me: String) {
+    println!("{}", log_me);
+}
+
+const POOL: &str = "damage numbers";
+const POOL_CAPACITY: usize = 1;
+const TWEEN_DURATION: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug)]
+struct MePolator;
+
+impl Interpolator for MePolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
@@ -1,7 +1,7 @@
 use bevy_tween::combinator::{AnimationBuilderExt, TargetState, event, sequence};
 use bevy_tween::interpolation::EaseKind;
 use bevy_tween::prelude::{Interpolator, IntoTarget};
-use bevy_tween::tween::TargetComponent;
+use bevy_tween::tween::{AnimationTarget, ComponentTween, TargetComponent};
 use bevy_tween_helpers::prelude::*;
 use std::time::Duration;
 
@@ -52,6 +52,45 @@ fn test_automatic_tween_destruction() {
     assert_eq!(parents_after_despawn, 0);
 }
 
+#[test]
+fn test_tween_destruction_on_target_despawn_after_target_removal() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tween.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        AnimationParentDestroyerGenericPlugin::<MePolator>::default(),
+    ));
+
+    app.update();
+
+    let tween_before_despawn = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+
+    app.add_systems(
+        Update,
+        despawn_target_entity.after(TweenHelpersSystemSet::TargetRemoval),
+    );
+
+    app.update();
+
+    let tween_after_despawn = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+    let parents_after_despawn = app
+        .world_mut()
+        .query::<&AnimationParentTag>()
+        .iter(app.world())
+        .len();
+
+    assert_eq!(tween_before_despawn, 1);
+    assert_eq!(tween_after_despawn, 0);
+    assert_eq!(parents_after_despawn, 0);
+}
+
 #[test]
 fn test_automatic_tween_destruction_on_event_mark() {
     let mut app = App::new();
@@ -151,6 +190,461 @@ fn test_automatic_event_emitting_tween_animation_parent_tagging() {
     assert_eq!(event_tagged_parents_after_despawn, 0);
 }
 
+#[test]
+fn test_marker_tween_target_removal() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_marker_tween.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+    ));
+
+    app.update();
+
+    let marked_target = app
+        .world_mut()
+        .query_filtered::<Entity, With<TargetEntityTag>>()
+        .single(app.world())
+        .unwrap();
+    let unrelated_target = app
+        .world_mut()
+        .query_filtered::<Entity, With<UnrelatedTargetTag>>()
+        .single(app.world())
+        .unwrap();
+    let tracked_targets_before_despawn: Vec<Entity> = app
+        .world_mut()
+        .query_filtered::<Entity, With<TweenTargetOf>>()
+        .iter(app.world())
+        .collect();
+    app.world_mut().entity_mut(unrelated_target).despawn();
+    app.update();
+
+    let tween_target_after_unrelated_despawn = app
+        .world_mut()
+        .query_filtered::<&ComponentTween<MePolator>, With<TweenTag>>()
+        .single(app.world())
+        .unwrap()
+        .target
+        .clone();
+    app.world_mut().entity_mut(marked_target).despawn();
+    app.update();
+
+    let tweens_after_marked_despawn = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+
+    assert_eq!(tracked_targets_before_despawn, vec![marked_target]);
+    assert!(matches!(
+        tween_target_after_unrelated_despawn,
+        TargetComponent::Marker { .. }
+    ));
+    assert_eq!(tweens_after_marked_despawn, 0);
+}
+
+#[test]
+fn test_target_tracking_follows_tween_despawn() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tween.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+    ));
+
+    app.update();
+
+    let tracked_targets_before_despawn = app
+        .world_mut()
+        .query::<&TweenTargetOf>()
+        .iter(app.world())
+        .len();
+    let links_before_despawn = app
+        .world_mut()
+        .query::<&LinkedTween>()
+        .iter(app.world())
+        .len();
+    let tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<TweenTag>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(tween).despawn();
+    app.update();
+
+    let tracked_targets_after_despawn = app
+        .world_mut()
+        .query::<&TweenTargetOf>()
+        .iter(app.world())
+        .len();
+    let links_after_despawn = app
+        .world_mut()
+        .query::<&LinkedTween>()
+        .iter(app.world())
+        .len();
+
+    assert_eq!(tracked_targets_before_despawn, 1);
+    assert_eq!(links_before_despawn, 1);
+    assert_eq!(tracked_targets_after_despawn, 0);
+    assert_eq!(links_after_despawn, 0);
+}
+
+#[test]
+fn test_hierarchy_target_removal_with_filter() {
+    let mut app = App::new();
+
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+    ));
+    let root = app.world_mut().spawn(TargetEntityTag).id();
+    let child = app.world_mut().spawn((TargetEntityTag, ChildOf(root))).id();
+    let grandchild = app
+        .world_mut()
+        .spawn((TargetEntityTag, FilteredTag, ChildOf(child)))
+        .id();
+    for target in [root, child, grandchild] {
+        let mut state = target.into_target().state(());
+        app.world_mut()
+            .commands()
+            .spawn(())
+            .animation()
+            .insert(tween_with_components(
+                Duration::from_secs_f32(30.0),
+                EaseKind::Linear,
+                state.with(move |_state| MePolator),
+                TweenTag,
+            ));
+    }
+    app.update();
+
+    app.world_mut()
+        .trigger(TweenRequest::RemoveTargetsFromHierarchies(
+            RemoveTargetsFromHierarchies::new(vec![root]).with_component_filter::<FilteredTag>(),
+        ));
+    app.update();
+    let tweens_after_filtered_removal =
+        app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+
+    app.world_mut()
+        .trigger(TweenRequest::RemoveTargetsFromHierarchies(
+            RemoveTargetsFromHierarchies::new(vec![root]),
+        ));
+    app.update();
+    let tweens_after_removal = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+
+    assert_eq!(tweens_after_filtered_removal, 2);
+    assert_eq!(tweens_after_removal, 0);
+}
+
+#[test]
+fn test_tween_removal_on_component_removal() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tween.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        RemoveTweensOnComponentRemoved::<FilteredTag, MePolator>::default(),
+    ));
+
+    app.update();
+
+    let target = app
+        .world_mut()
+        .query_filtered::<Entity, With<TargetEntityTag>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(target).insert(FilteredTag);
+    app.update();
+
+    let tweens_before_removal = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+    app.world_mut().entity_mut(target).remove::<FilteredTag>();
+    app.update();
+
+    let tweens_after_removal = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+    let target_still_exists = app.world().get_entity(target).is_ok();
+
+    assert_eq!(tweens_before_removal, 1);
+    assert_eq!(tweens_after_removal, 0);
+    assert!(target_still_exists);
+}
+
+#[test]
+fn test_plugins_can_be_added_after_being_added_for_you() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tween.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        RemoveTweensOnComponentRemoved::<FilteredTag, MePolator>::default(),
+        TweenTargetRemover::<MePolator>::default(),
+        TweenTargetTracker::<MePolator>::default(),
+    ));
+
+    app.update();
+
+    let target = app
+        .world_mut()
+        .query_filtered::<Entity, With<TargetEntityTag>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(target).insert(FilteredTag);
+    app.update();
+    app.world_mut().entity_mut(target).remove::<FilteredTag>();
+    app.update();
+
+    let tweens_after_removal = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+    assert_eq!(tweens_after_removal, 0);
+}
+
+#[test]
+fn test_mass_target_despawn() {
+    let mut app = App::new();
+
+    app.insert_resource(TweeningLoggingFunction(None));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        AnimationParentDestroyerGenericPlugin::<MePolator>::default(),
+    ));
+    let targets: Vec<Entity> = (0..MASS_DESPAWN_TARGETS_COUNT)
+        .map(|_| app.world_mut().spawn(TargetEntityTag).id())
+        .collect();
+    let survivor = app.world_mut().spawn(()).id();
+    for target in &targets {
+        let mut state = target.into_target().state(());
+        app.world_mut()
+            .commands()
+            .spawn(AnimationParentTag)
+            .animation()
+            .insert(tween_with_components(
+                Duration::from_secs_f32(30.0),
+                EaseKind::Linear,
+                state.with(move |_state| MePolator),
+                TweenTag,
+            ));
+    }
+    let mut shared_targets = targets.clone();
+    shared_targets.push(survivor);
+    let mut shared_state = TargetComponent::Entities(shared_targets).state(());
+    app.world_mut()
+        .commands()
+        .spawn(AnimationParentTag)
+        .animation()
+        .insert(tween_with_components(
+            Duration::from_secs_f32(30.0),
+            EaseKind::Linear,
+            shared_state.with(move |_state| MePolator),
+            TweenTag,
+        ));
+    app.update();
+
+    let tweens_before_despawn = app.world_mut().query::<&TweenTag>().iter(app.world()).len();
+    for target in &targets {
+        app.world_mut().entity_mut(*target).despawn();
+    }
+    app.update();
+
+    let remaining_tweens: Vec<&ComponentTween<MePolator>> = app
+        .world_mut()
+        .query_filtered::<&ComponentTween<MePolator>, With<TweenTag>>()
+        .iter(app.world())
+        .collect();
+    let remaining_tweens_count = remaining_tweens.len();
+    let remaining_tween_targets_survivor_only = matches!(
+        &remaining_tweens[0].target,
+        TargetComponent::Entities(remaining_targets) if remaining_targets == &vec![survivor]
+    );
+    let parents_after_despawn = app
+        .world_mut()
+        .query::<&AnimationParentTag>()
+        .iter(app.world())
+        .len();
+
+    assert_eq!(tweens_before_despawn, MASS_DESPAWN_TARGETS_COUNT + 1);
+    assert_eq!(remaining_tweens_count, 1);
+    assert!(remaining_tween_targets_survivor_only);
+    assert_eq!(parents_after_despawn, 1);
+}
+
+#[test]
+fn test_animation_parent_despawn_reasons() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tweens_for_event_parent_test.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.init_resource::<DespawnedAnimationParents>();
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        AnimationParentDestroyerGenericPlugin::<MePolator>::default(),
+    ));
+    app.add_observer(
+        |trigger: On<AnimationParentDespawned>,
+         parents: Query<(), With<AnimationParentToDestroyIfOnlyHasEventsLeft>>,
+         mut despawned_parents: ResMut<DespawnedAnimationParents>| {
+            // the event is triggered before the parent is despawned
+            assert!(parents.contains(trigger.entity));
+            despawned_parents.0.push(trigger.reason);
+        },
+    );
+
+    app.update();
+    app.add_systems(
+        Update,
+        despawn_target_entity
+            .after(spawn_tweens_for_event_parent_test)
+            .before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.update();
+
+    let despawn_messages: Vec<AnimationParentDespawnReason> = app
+        .world()
+        .resource::<Messages<AnimationParentDespawned>>()
+        .iter_current_update_messages()
+        .map(|animation_parent_despawned| animation_parent_despawned.reason)
+        .collect();
+
+    assert_eq!(
+        app.world().resource::<DespawnedAnimationParents>().0,
+        vec![AnimationParentDespawnReason::OnlyEventsLeft]
+    );
+    assert_eq!(
+        despawn_messages,
+        vec![AnimationParentDespawnReason::OnlyEventsLeft]
+    );
+}
+
+#[test]
+fn test_residual_children_do_not_keep_parents_alive() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tweens_with_cues.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        AnimationParentDestroyerGenericPlugin::<MePolator>::default(),
+    ));
+    app.register_residual_tween_child::<With<CueTag>>();
+
+    app.update();
+    let parents_before_despawn = app
+        .world_mut()
+        .query::<&AnimationParentTag>()
+        .iter(app.world())
+        .len();
+
+    app.add_systems(
+        Update,
+        despawn_target_entity
+            .after(spawn_tweens_with_cues)
+            .before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.update();
+
+    let parents_after_despawn = app
+        .world_mut()
+        .query::<&AnimationParentTag>()
+        .iter(app.world())
+        .len();
+    let despawn_messages: Vec<AnimationParentDespawnReason> = app
+        .world()
+        .resource::<Messages<AnimationParentDespawned>>()
+        .iter_current_update_messages()
+        .map(|animation_parent_despawned| animation_parent_despawned.reason)
+        .collect();
+
+    assert_eq!(parents_before_despawn, 1);
+    assert_eq!(parents_after_despawn, 0);
+    assert_eq!(
+        despawn_messages,
+        vec![AnimationParentDespawnReason::OnlyResidualChildrenLeft]
+    );
+}
+
+#[test]
+fn test_type_erased_animation_parent_destruction() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tween.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        TypeErasedAnimationParentDestroyerPlugin,
+        // steps aside for the type erased observer
+        AnimationParentDestroyerGenericPlugin::<MePolator>::default(),
+    ));
+
+    app.update();
+    let parents_before_despawn = app
+        .world_mut()
+        .query::<&AnimationParentTag>()
+        .iter(app.world())
+        .len();
+
+    app.add_systems(
+        Update,
+        despawn_target_entity
+            .after(spawn_tween)
+            .before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.update();
+
+    let parents_after_despawn = app
+        .world_mut()
+        .query::<&AnimationParentTag>()
+        .iter(app.world())
+        .len();
+    let despawn_messages: Vec<AnimationParentDespawnReason> = app
+        .world()
+        .resource::<Messages<AnimationParentDespawned>>()
+        .iter_current_update_messages()
+        .map(|animation_parent_despawned| animation_parent_despawned.reason)
+        .collect();
+
+    assert_eq!(parents_before_despawn, 1);
+    assert_eq!(parents_after_despawn, 0);
+    assert_eq!(
+        despawn_messages,
+        vec![AnimationParentDespawnReason::NoChildrenLeft]
+    );
+}
+
 fn log(log_me: String) {
     println!("{}", log_me);
 }
@@ -174,6 +668,47 @@ fn spawn_tween(mut commands: Commands, target_entities: Query<&TargetEntityTag>)
         ));
 }
 
+fn spawn_tweens_with_cues(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let entity = commands.spawn(TargetEntityTag).id();
+    let mut state = entity.into_target().state(());
+
+    let animation_parent = commands.spawn(AnimationParentTag).id();
+    commands
+        .entity(animation_parent)
+        .animation()
+        .insert(tween_with_components(
+            Duration::from_secs_f32(30.0),
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            TweenTag,
+        ));
+    commands.spawn((CueTag, ChildOf(animation_parent)));
+}
+
+fn spawn_marker_tween(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let marked_target = commands.spawn((TargetEntityTag, AnimationTarget)).id();
+    commands.spawn((UnrelatedTargetTag, AnimationTarget));
+    let mut state = AnimationTarget.into_target().state(());
+
+    commands
+        .spawn(ChildOf(marked_target))
+        .animation()
+        .insert(tween_with_components(
+            Duration::from_secs_f32(30.0),
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            TweenTag,
+        ));
+}
+
 fn spawn_tweens_for_event_parent_test(
     mut commands: Commands,
     target_entities: Query<&TargetEntityTag>,
@@ -220,12 +755,26 @@ fn despawn_target_entity(
     }
 }
 
+const MASS_DESPAWN_TARGETS_COUNT: usize = 500;
+
+#[derive(Resource, Default)]
+struct DespawnedAnimationParents(Vec<AnimationParentDespawnReason>);
+
 #[derive(Component)]
 struct TargetEntityTag;
 
+#[derive(Component)]
+struct UnrelatedTargetTag;
+
 #[derive(Component)]
 struct TweenTag;
 
+#[derive(Component)]
+struct FilteredTag;
+
+#[derive(Component)]
+struct CueTag;
+
 #[derive(Component)]
 struct AnimationParentTag;
 
@@ -1,8 +1,8 @@
 use bevy::ecs::system::ScheduleSystem;
-use bevy_tween::combinator::AnimationBuilderExt;
+use bevy_tween::combinator::{AnimationBuilderExt, parallel, sequence};
 use bevy_tween::interpolation::EaseKind;
-use bevy_tween::prelude::{Interpolator, IntoTarget};
-use bevy_tween::tween::ComponentTween;
+use bevy_tween::prelude::{Interpolator, IntoTarget, Repeat};
+use bevy_tween::tween::{ComponentTween, SkipTween, TargetComponent};
 use bevy_tween_helpers::prelude::*;
 use std::time::Duration;
 
@@ -30,6 +30,359 @@ fn test_tween_priority_through_parent() {
     app.update();
 }
 
+#[test]
+fn test_tweens_on_different_channels_coexist() {
+    let mut app = make_app_with_systems(
+        spawn_tweens_with_priorities_on_different_channels,
+        assert_both_still_exist,
+    );
+    app.update();
+}
+
+#[test]
+fn test_declared_conflict_between_interpolator_types() {
+    let mut app = make_app_with_systems(
+        spawn_tweens_of_conflicting_types,
+        assert_destruction_of_b_of_other_type,
+    );
+    app.add_plugins(TweenTargetRemover::<OtherPolator>::default())
+        .declare_tween_conflict::<MePolator, OtherPolator>();
+    app.update();
+}
+
+#[test]
+fn test_declared_conflict_with_explicitly_added_handlers() {
+    let mut app = make_app_with_systems(
+        spawn_tweens_of_conflicting_types,
+        assert_destruction_of_b_of_other_type,
+    );
+    app.add_plugins(TweenTargetRemover::<OtherPolator>::default())
+        .declare_tween_conflict::<MePolator, OtherPolator>()
+        .add_plugins(TweenPriorityHandler::<OtherPolator>::default());
+    app.update();
+}
+
+#[test]
+fn test_sibling_tweens_ignore_priorities_by_default() {
+    let mut app = make_app_with_systems(
+        |commands: Commands| spawn_sibling_tweens_with_priorities(commands, false),
+        assert_both_still_exist,
+    );
+    app.update();
+}
+
+#[test]
+fn test_sibling_tweens_with_opted_in_priorities() {
+    let mut app = make_app_with_systems(
+        |commands: Commands| spawn_sibling_tweens_with_priorities(commands, true),
+        assert_destruction_of_b,
+    );
+    app.update();
+}
+
+#[test]
+fn test_equal_priority_siblings_newest_start_wins() {
+    let mut app = make_app_with_systems(
+        spawn_equal_priority_siblings_with_staggered_starts,
+        assert_destruction_of_b,
+    );
+    app.update();
+}
+
+#[test]
+fn test_equal_priority_siblings_oldest_start_wins() {
+    let mut app = make_app_with_systems(
+        spawn_equal_priority_siblings_with_staggered_starts,
+        assert_destruction_of_a,
+    );
+    app.insert_resource(TweenPrioritySettings {
+        tie_break: TweenPriorityTieBreak::OldestWins,
+        ..default()
+    });
+    app.update();
+}
+
+#[test]
+fn test_tweens_that_never_overlap_in_time_coexist_if_configured() {
+    let mut app = make_app_with_systems(
+        spawn_tweens_with_priorities_at_different_times,
+        assert_both_still_exist,
+    );
+    app.insert_resource(TweenPrioritySettings {
+        preempt_only_overlapping_tweens: true,
+        ..default()
+    });
+    app.update();
+}
+
+#[test]
+fn test_tweens_that_never_overlap_in_time_conflict_by_default() {
+    let mut app = make_app_with_systems(
+        spawn_tweens_with_priorities_at_different_times,
+        assert_destruction_of_b,
+    );
+    app.update();
+}
+
+#[test]
+fn test_repeating_tweens_overlap_later_tweens() {
+    let mut app = make_app_with_systems(
+        spawn_repeating_tween_and_later_tween_with_priorities,
+        assert_destruction_of_b,
+    );
+    app.insert_resource(TweenPrioritySettings {
+        preempt_only_overlapping_tweens: true,
+        ..default()
+    });
+    app.update();
+}
+
+#[test]
+fn test_priority_inserted_at_runtime() {
+    let mut app = make_app_with_systems(spawn_tween_without_priority_once, || {});
+    app.update();
+
+    let tween_without_priority = app
+        .world_mut()
+        .query_filtered::<Entity, With<A>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .entity_mut(tween_without_priority)
+        .insert(TweenPriorityToOthersOfType(10));
+    app.update();
+
+    let a_tweens = app
+        .world_mut()
+        .query_filtered::<(), (With<A>, With<ComponentTween<MePolator>>)>()
+        .iter(app.world())
+        .count();
+    let b_tweens = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, With<ComponentTween<MePolator>>)>()
+        .iter(app.world())
+        .count();
+    assert_eq!(a_tweens, 1);
+    assert_eq!(b_tweens, 0);
+}
+
+#[test]
+fn test_same_frame_equal_priorities_newest_wins() {
+    let mut app =
+        make_app_with_systems(spawn_tweens_with_equal_priorities, assert_destruction_of_a);
+    app.update();
+}
+
+#[test]
+fn test_same_frame_equal_priorities_oldest_wins() {
+    let mut app =
+        make_app_with_systems(spawn_tweens_with_equal_priorities, assert_destruction_of_b);
+    app.insert_resource(TweenPrioritySettings {
+        tie_break: TweenPriorityTieBreak::OldestWins,
+        ..default()
+    });
+    app.update();
+}
+
+#[test]
+fn test_same_frame_equal_priorities_reject_newcomer() {
+    let mut app =
+        make_app_with_systems(spawn_tweens_with_equal_priorities, assert_destruction_of_b);
+    app.insert_resource(TweenPrioritySettings {
+        tie_break: TweenPriorityTieBreak::RejectNewcomer,
+        ..default()
+    });
+    app.update();
+}
+
+#[test]
+fn test_suspended_tween_resumes_once_dominant_is_gone() {
+    let mut app = make_app_with_systems(spawn_tweens_with_suspendable_weaker_tween, || {});
+    app.update();
+
+    let suspended_tweens_after_spawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, With<SkipTween>, With<SuspendedTweenTargets>)>()
+        .iter(app.world())
+        .count();
+    let dominant_tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<A>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(dominant_tween).despawn();
+    app.update();
+
+    let suspended_tweens_after_dominant_despawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, Or<(With<SkipTween>, With<SuspendedTweenTargets>)>)>()
+        .iter(app.world())
+        .count();
+    let weaker_tweens_after_dominant_despawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, With<ComponentTween<MePolator>>)>()
+        .iter(app.world())
+        .count();
+
+    assert_eq!(suspended_tweens_after_spawn, 1);
+    assert_eq!(suspended_tweens_after_dominant_despawn, 0);
+    assert_eq!(weaker_tweens_after_dominant_despawn, 1);
+}
+
+#[test]
+fn test_stacking_a_sibling_leaves_the_shared_parent_running() {
+    let mut app = make_app_with_systems(spawn_stackable_sibling_tweens, || {});
+    app.update();
+
+    let weaker_tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<B>>()
+        .single(app.world())
+        .unwrap();
+    let shared_parent_paused = weaker_tween_time_runner_is_paused(&mut app, weaker_tween);
+    let interrupted_tweens_after_spawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, With<SkipTween>, With<StackedTweenTargets>)>()
+        .iter(app.world())
+        .count();
+    let dominant_tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<A>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(dominant_tween).despawn();
+    app.update();
+
+    let interrupted_tweens_after_dominant_despawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, Or<(With<SkipTween>, With<StackedTweenTargets>)>)>()
+        .iter(app.world())
+        .count();
+
+    assert!(!shared_parent_paused);
+    assert_eq!(interrupted_tweens_after_spawn, 1);
+    assert_eq!(interrupted_tweens_after_dominant_despawn, 0);
+    assert!(!weaker_tween_time_runner_is_paused(&mut app, weaker_tween));
+}
+
+#[test]
+fn test_preemption_is_announced_to_loser_and_its_parent() {
+    let mut app = make_app_with_systems(spawn_tweens_with_priorities, assert_preemption_message);
+    app.init_resource::<ObservedPreemptions>();
+    app.add_observer(
+        |preempted: On<TweenPreempted>, mut observed: ResMut<ObservedPreemptions>| {
+            observed.0.push((preempted.entity, preempted.loser));
+        },
+    );
+    app.update();
+
+    let observed = &app.world().resource::<ObservedPreemptions>().0;
+    assert_eq!(observed.len(), 2);
+    let (weaker_tween, _) = observed[0];
+    let (weaker_tween_parent, _) = observed[1];
+
+    assert!(observed.iter().all(|(_, loser)| *loser == weaker_tween));
+    assert!(app.world().get_entity(weaker_tween).is_err());
+    assert!(app.world().get_entity(weaker_tween_parent).is_ok());
+}
+
+#[test]
+fn test_stacked_tween_regains_control_once_dominant_is_gone() {
+    let mut app = make_app_with_systems(spawn_stackable_weaker_tween_then_dominant_tween, || {});
+    app.update();
+    app.update();
+
+    let weaker_tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<B>>()
+        .single(app.world())
+        .unwrap();
+    let interrupted_parent_paused = weaker_tween_time_runner_is_paused(&mut app, weaker_tween);
+    let interrupted_tweens_after_dominant_spawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, With<SkipTween>, With<StackedTweenTargets>)>()
+        .iter(app.world())
+        .count();
+    let stacks_after_dominant_spawn = app
+        .world_mut()
+        .query::<&TweenPriorityStack<MePolator>>()
+        .iter(app.world())
+        .map(|stack| {
+            stack
+                .top()
+                .map(|interrupted_tween| interrupted_tween.tween_entity)
+        })
+        .collect::<Vec<_>>();
+    let dominant_tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<A>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(dominant_tween).despawn();
+    app.update();
+
+    let interrupted_tweens_after_dominant_despawn = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, Or<(With<SkipTween>, With<StackedTweenTargets>)>)>()
+        .iter(app.world())
+        .count();
+
+    assert!(interrupted_parent_paused);
+    assert_eq!(interrupted_tweens_after_dominant_spawn, 1);
+    assert_eq!(stacks_after_dominant_spawn, vec![Some(weaker_tween)]);
+    assert_eq!(interrupted_tweens_after_dominant_despawn, 0);
+    assert!(!weaker_tween_time_runner_is_paused(&mut app, weaker_tween));
+}
+
+fn weaker_tween_time_runner_is_paused(app: &mut App, weaker_tween: Entity) -> bool {
+    let parent = app.world().get::<ChildOf>(weaker_tween).unwrap().parent();
+    app.world()
+        .get::<bevy_time_runner::TimeRunner>(parent)
+        .unwrap()
+        .paused()
+}
+
+#[test]
+fn test_retargeted_tween_competes_on_its_new_target() {
+    let mut app = make_app_with_systems(spawn_tweens_with_priorities_on_different_targets, || {});
+    app.update();
+
+    let dominant_tween_target = single_tween_target_with::<A>(&mut app);
+    let weaker_tween_target = single_tween_target_with::<B>(&mut app);
+    let weaker_tween = app
+        .world_mut()
+        .query_filtered::<Entity, With<B>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .write_message(TweenRequest::ReplaceTarget(ReplaceTweenTarget {
+            tween_entity: weaker_tween,
+            target_to_replace: weaker_tween_target,
+            replacement: dominant_tween_target,
+        }));
+    app.update();
+
+    let weaker_tweens = app
+        .world_mut()
+        .query_filtered::<(), (With<B>, With<ComponentTween<MePolator>>)>()
+        .iter(app.world())
+        .count();
+    assert_ne!(dominant_tween_target, weaker_tween_target);
+    assert_eq!(weaker_tweens, 0);
+}
+
+fn single_tween_target_with<C: Component>(app: &mut App) -> Entity {
+    let tween = app
+        .world_mut()
+        .query_filtered::<&ComponentTween<MePolator>, With<C>>()
+        .single(app.world())
+        .unwrap();
+    match tween.target {
+        TargetComponent::Entity(target) => target,
+        _ => panic!("expected a single target"),
+    }
+}
+
 fn make_app_with_systems<M, N>(
     setup_system: impl IntoScheduleConfigs<ScheduleSystem, M>,
     assertion_system: impl IntoScheduleConfigs<ScheduleSystem, N>,
@@ -84,6 +437,334 @@ fn assert_destruction_of_b(
     assert_eq!(b_interpolators.iter().count(), 0);
 }
 
+fn assert_preemption_message(
+    mut preemptions: MessageReader<TweenPreempted>,
+    a_tweens: Query<Entity, With<A>>,
+) {
+    let preemptions: Vec<&TweenPreempted> = preemptions.read().collect();
+    assert_eq!(preemptions.len(), 1);
+    assert_eq!(preemptions[0].winner, a_tweens.single().unwrap());
+    assert_eq!(preemptions[0].targets.len(), 1);
+}
+
+#[derive(Resource, Default)]
+struct ObservedPreemptions(Vec<(Entity, Entity)>);
+
+fn assert_destruction_of_a(
+    a_interpolators: Query<&ComponentTween<MePolator>, With<A>>,
+    b_interpolators: Query<&ComponentTween<MePolator>, With<B>>,
+) {
+    assert_eq!(a_interpolators.iter().count(), 0);
+    assert_eq!(b_interpolators.iter().count(), 1);
+}
+
+fn spawn_tweens_with_equal_priorities(mut commands: Commands) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(10), A),
+    ));
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(10), B),
+    ));
+}
+
+fn spawn_tweens_with_suspendable_weaker_tween(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(10), A),
+    ));
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(9), TweenPreemption::Suspend, B),
+    ));
+}
+
+fn spawn_stackable_weaker_tween_then_dominant_tween(
+    mut commands: Commands,
+    mut target: Local<Option<Entity>>,
+    mut spawned_dominant: Local<bool>,
+) {
+    let Some(entity) = *target else {
+        let entity = commands.spawn(()).id();
+        let mut state = entity.into_target().state(());
+        commands
+            .spawn(TweenPreemption::Stack)
+            .animation()
+            .insert(tween_with_components(
+                TWEEN_DURATION,
+                EaseKind::Linear,
+                state.with(move |_state| MePolator),
+                (TweenPriorityToOthersOfType(9), B),
+            ));
+        *target = Some(entity);
+        return;
+    };
+    if !*spawned_dominant {
+        *spawned_dominant = true;
+        let mut state = entity.into_target().state(());
+        commands.spawn(()).animation().insert(tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            (TweenPriorityToOthersOfType(10), A),
+        ));
+    }
+}
+
+fn spawn_tweens_with_priorities_on_different_targets(
+    mut commands: Commands,
+    mut spawned: Local<bool>,
+) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let dominant_tween_target = commands.spawn(()).id();
+    let mut dominant_tween_state = dominant_tween_target.into_target().state(());
+    let weaker_tween_target = commands.spawn(()).id();
+    let mut weaker_tween_state = weaker_tween_target.into_target().state(());
+
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        dominant_tween_state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(10), A),
+    ));
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        weaker_tween_state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(9), B),
+    ));
+}
+
+fn spawn_tweens_with_priorities_on_different_channels(mut commands: Commands) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands
+        .spawn(())
+        .animation()
+        .insert(tween_with_priority_on_channel(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            (state.with(move |_state| MePolator), A),
+            10,
+            "hover",
+        ));
+    commands
+        .spawn((TweenPriorityToOthersOfType(9), TweenChannel("damage")))
+        .animation()
+        .insert(tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            B,
+        ));
+}
+
+fn spawn_tweens_of_conflicting_types(mut commands: Commands) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(10), A),
+    ));
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| OtherPolator),
+        (TweenPriorityToOthersOfType(9), B),
+    ));
+}
+
+fn assert_destruction_of_b_of_other_type(
+    a_interpolators: Query<&ComponentTween<MePolator>, With<A>>,
+    b_interpolators: Query<&ComponentTween<OtherPolator>, With<B>>,
+) {
+    assert_eq!(a_interpolators.iter().count(), 1);
+    assert_eq!(b_interpolators.iter().count(), 0);
+}
+
+fn spawn_sibling_tweens_with_priorities(mut commands: Commands, resolve_siblings: bool) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    let mut animation_parent = commands.spawn(());
+    if resolve_siblings {
+        animation_parent.insert(ResolveSiblingTweenPriorities);
+    }
+    animation_parent.animation().insert(parallel((
+        tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            (TweenPriorityToOthersOfType(10), A),
+        ),
+        tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            (TweenPriorityToOthersOfType(9), B),
+        ),
+    )));
+}
+
+fn spawn_stackable_sibling_tweens(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands
+        .spawn((ResolveSiblingTweenPriorities, TweenPreemption::Stack))
+        .animation()
+        .insert(parallel((
+            tween_with_components(
+                TWEEN_DURATION,
+                EaseKind::Linear,
+                state.with(move |_state| MePolator),
+                (TweenPriorityToOthersOfType(10), A),
+            ),
+            tween_with_components(
+                TWEEN_DURATION,
+                EaseKind::Linear,
+                state.with(move |_state| MePolator),
+                (TweenPriorityToOthersOfType(9), B),
+            ),
+        )));
+}
+
+/// A is spawned first (and thus has the lower entity index) but starts after B,
+/// so only the comparison of their starts tells that A is the newer one
+fn spawn_equal_priority_siblings_with_staggered_starts(mut commands: Commands) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands
+        .spawn(ResolveSiblingTweenPriorities)
+        .animation()
+        .insert(parallel((
+            sequence((
+                wait_for(TWEEN_DURATION / 2),
+                tween_with_components(
+                    TWEEN_DURATION,
+                    EaseKind::Linear,
+                    state.with(move |_state| MePolator),
+                    (TweenPriorityToOthersOfType(10), A),
+                ),
+            )),
+            tween_with_components(
+                TWEEN_DURATION,
+                EaseKind::Linear,
+                state.with(move |_state| MePolator),
+                (TweenPriorityToOthersOfType(10), B),
+            ),
+        )));
+}
+
+fn spawn_tweens_with_priorities_at_different_times(mut commands: Commands) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(10), A),
+    ));
+    commands.spawn(()).animation().insert(sequence((
+        wait_for(TWEEN_DURATION * 2),
+        tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            (TweenPriorityToOthersOfType(9), B),
+        ),
+    )));
+}
+
+fn spawn_repeating_tween_and_later_tween_with_priorities(mut commands: Commands) {
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands
+        .spawn(())
+        .animation()
+        .repeat(Repeat::Infinitely)
+        .insert(tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            (TweenPriorityToOthersOfType(10), A),
+        ));
+    commands.spawn(()).animation().insert(sequence((
+        wait_for(TWEEN_DURATION * 2),
+        tween_with_components(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+            (TweenPriorityToOthersOfType(9), B),
+        ),
+    )));
+}
+
+fn spawn_tween_without_priority_once(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let entity = commands.spawn(()).id();
+    let animation_target = entity.into_target();
+    let mut state = animation_target.state(());
+
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        A,
+    ));
+    commands.spawn(()).animation().insert(tween_with_components(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        (TweenPriorityToOthersOfType(9), B),
+    ));
+}
+
 fn spawn_tween_with_priority_and_one_without(mut commands: Commands) {
     let entity = commands.spawn(()).id();
     let animation_target = entity.into_target();
@@ -151,3 +832,12 @@ impl Interpolator for MePolator {
 
     fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
 }
+
+#[derive(Clone, Copy, Debug)]
+struct OtherPolator;
+
+impl Interpolator for OtherPolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
@@ -0,0 +1,210 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy_tween::combinator::{AnimationBuilderExt, tween};
+use bevy_tween::interpolation::EaseKind;
+use bevy_tween::prelude::{Interpolator, IntoTarget};
+use bevy_tween_helpers::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn test_tween_query_on_animated_entity() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tweens_with_priorities.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetTracker::<MePolator>::default(),
+    ));
+
+    app.update();
+
+    let (tweens_targeting, animation_parents, mut priorities, progresses) = app
+        .world_mut()
+        .run_system_once(
+            |tween_query: TweenQuery<MePolator>, targets: Query<Entity, With<TargetTag>>| {
+                let target = targets.single().unwrap();
+                let tweens: Vec<Entity> = tween_query.tweens_targeting(target).collect();
+                (
+                    tweens.len(),
+                    tween_query.animation_parents_of(target).len(),
+                    tweens
+                        .iter()
+                        .map(|tween| tween_query.priority_of(*tween))
+                        .collect::<Vec<_>>(),
+                    tweens
+                        .iter()
+                        .map(|tween| tween_query.progress_of(*tween))
+                        .collect::<Vec<_>>(),
+                )
+            },
+        )
+        .unwrap();
+    let animating_states = app
+        .world_mut()
+        .run_system_once(
+            |tween_query: TweenQuery<MePolator>,
+             targets: Query<Entity, With<TargetTag>>,
+             untargeted: Query<Entity, With<UntargetedTag>>| {
+                (
+                    tween_query.is_animating(targets.single().unwrap()),
+                    tween_query.is_animating(untargeted.single().unwrap()),
+                )
+            },
+        )
+        .unwrap();
+    priorities.sort();
+
+    assert_eq!(tweens_targeting, 2);
+    assert_eq!(animation_parents, 2);
+    assert_eq!(priorities, vec![Some(3), Some(7)]);
+    assert_eq!(progresses, vec![Some(0.0), Some(0.0)]);
+    assert_eq!(animating_states, (true, false));
+}
+
+#[test]
+fn test_typed_target_tracking() {
+    let mut app = App::new();
+
+    app.add_systems(
+        Update,
+        spawn_tweens_of_two_types.before(TweenHelpersSystemSet::PreTargetRemoval),
+    );
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<MePolator>::default(),
+        TweenTargetRemover::<OtherPolator>::default(),
+    ));
+
+    app.update();
+
+    let target = app
+        .world_mut()
+        .query_filtered::<Entity, With<TargetTag>>()
+        .single(app.world())
+        .unwrap();
+    let (mut animating_types, me_polator_tweens) = app
+        .world_mut()
+        .run_system_once(move |target_index: TweenTargetIndex| {
+            (
+                target_index
+                    .interpolator_types_animating(target)
+                    .iter()
+                    .map(|tween_type| tween_type.type_name)
+                    .collect::<Vec<_>>(),
+                target_index
+                    .tweens_of_type_targeting::<MePolator>(target)
+                    .count(),
+            )
+        })
+        .unwrap();
+    animating_types.sort();
+
+    app.world_mut()
+        .trigger(RemoveTargetsFromAllTweensOfType::<OtherPolator>::new(vec![
+            target,
+        ]));
+    app.update();
+    let types_after_removal = app
+        .world_mut()
+        .run_system_once(move |target_index: TweenTargetIndex| {
+            target_index
+                .interpolator_types_animating(target)
+                .iter()
+                .map(|tween_type| tween_type.type_name)
+                .collect::<Vec<_>>()
+        })
+        .unwrap();
+
+    assert_eq!(
+        animating_types,
+        vec![
+            std::any::type_name::<MePolator>(),
+            std::any::type_name::<OtherPolator>()
+        ]
+    );
+    assert_eq!(me_polator_tweens, 1);
+    assert_eq!(
+        types_after_removal,
+        vec![std::any::type_name::<MePolator>()]
+    );
+}
+
+fn log(log_me: String) {
+    println!("{}", log_me);
+}
+
+fn spawn_tweens_with_priorities(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    commands.spawn(UntargetedTag);
+    let entity = commands.spawn(TargetTag).id();
+    let mut state = entity.into_target().state(());
+
+    commands.spawn(()).animation().insert(tween_with_priority(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+        7,
+    ));
+    commands
+        .spawn(TweenPriorityToOthersOfType(3))
+        .animation()
+        .insert(tween(
+            TWEEN_DURATION,
+            EaseKind::Linear,
+            state.with(move |_state| MePolator),
+        ));
+}
+
+fn spawn_tweens_of_two_types(mut commands: Commands, mut spawned: Local<bool>) {
+    if *spawned {
+        return;
+    }
+    *spawned = true;
+    let entity = commands.spawn(TargetTag).id();
+    let mut state = entity.into_target().state(());
+
+    commands.spawn(()).animation().insert(tween(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| MePolator),
+    ));
+    commands.spawn(()).animation().insert(tween(
+        TWEEN_DURATION,
+        EaseKind::Linear,
+        state.with(move |_state| OtherPolator),
+    ));
+}
+
+const TWEEN_DURATION: Duration = Duration::from_secs(5);
+
+#[derive(Component)]
+struct TargetTag;
+
+#[derive(Component)]
+struct UntargetedTag;
+
+#[derive(Clone, Copy, Debug)]
+struct MePolator;
+
+impl Interpolator for MePolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
+
+#[derive(Clone, Copy, Debug)]
+struct OtherPolator;
+
+impl Interpolator for OtherPolator {
+    type Item = ();
+
+    fn interpolate(&self, _item: &mut Self::Item, _value: f32, _previous_value: f32) {}
+}
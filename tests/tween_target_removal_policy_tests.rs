@@ -0,0 +1,157 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy_tween::DefaultTweenPlugins;
+use bevy_tween::combinator::{AnimationBuilderExt, TransformTargetStateExt, tween};
+use bevy_tween::interpolate::Translation;
+use bevy_tween::interpolation::EaseKind;
+use bevy_tween::prelude::{Interpolator, IntoTarget};
+use bevy_tween::tween::{ComponentTween, SkipTween, TweenInterpolationValue, TweenPreviousValue};
+use bevy_tween_helpers::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn test_delta_tween_snaps_to_end_on_removal() {
+    let mut app = make_app();
+    let target = spawn_half_done_tween(&mut app, true, TweenTargetRemovalPolicy::SnapToEnd);
+
+    app.world_mut()
+        .trigger(RemoveTargetsFromAllTweensOfType::<Fade>::new(vec![target]));
+    app.update();
+
+    assert!((app.world().get::<Opacity>(target).unwrap().0 - 1.3).abs() < 1e-5);
+}
+
+#[test]
+fn test_tween_restores_start_on_requested_removal() {
+    let mut app = make_app();
+    let target = spawn_half_done_tween(&mut app, false, TweenTargetRemovalPolicy::LeaveAsIs);
+
+    app.world_mut().trigger(
+        RemoveTargetsFromAllTweensOfType::<Fade>::new(vec![target])
+            .with_removal_policy(TweenTargetRemovalPolicy::RestoreStart),
+    );
+    app.update();
+
+    assert_eq!(app.world().get::<Opacity>(target).unwrap().0, 0.0);
+}
+
+#[test]
+fn test_skipped_delta_tween_snaps_to_end_from_last_applied_value() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .insert_resource(TweeningLoggingFunction(Some(log)))
+        .add_plugins((
+            DefaultTweenPlugins::<()>::in_schedule(PostUpdate.intern()),
+            TweenRequestPlugin,
+            BevyTweenHelpersSystemSetsPlugin,
+            TweenTargetRemover::<Translation>::default(),
+            RemovedTweenTargetSettler::<Translation>::default(),
+        ));
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let mut transform_state = target.into_target().transform_state(Transform::default());
+    app.world_mut()
+        .commands()
+        .spawn(TweenTargetRemovalPolicy::SnapToEnd)
+        .animation()
+        .insert(tween(
+            Duration::from_secs(2),
+            EaseKind::Linear,
+            transform_state.translation_delta_to(Vec3::X),
+        ));
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(0.5));
+    app.update();
+    let translation_before_skip = app.world().get::<Transform>(target).unwrap().translation.x;
+
+    let tween_entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ComponentTween<Translation>>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().entity_mut(tween_entity).insert(SkipTween);
+    // the time advanced by during the first update is also advanced by in every update after it
+    app.update();
+    let interpolation_value_while_skipped = app
+        .world()
+        .get::<TweenInterpolationValue>(tween_entity)
+        .unwrap()
+        .0;
+
+    app.world_mut()
+        .trigger(RemoveTargetsFromAllTweensOfType::<Translation>::new(vec![
+            target,
+        ]));
+    app.update();
+
+    assert!((translation_before_skip - 0.25).abs() < 1e-5);
+    assert!((interpolation_value_while_skipped - 0.5).abs() < 1e-5);
+    assert!((app.world().get::<Transform>(target).unwrap().translation.x - 1.0).abs() < 1e-5);
+}
+
+fn make_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(TweeningLoggingFunction(Some(log)));
+    app.add_plugins((
+        TweenRequestPlugin,
+        BevyTweenHelpersSystemSetsPlugin,
+        TweenTargetRemover::<Fade>::default(),
+        RemovedTweenTargetSettler::<Fade>::default(),
+    ));
+    app
+}
+
+/// Spawns a tween from 0 to 1 that's halfway through (having set the opacity to 0.5),
+/// the delta one having started from 0.3 instead (thus at 0.8)
+fn spawn_half_done_tween(
+    app: &mut App,
+    delta: bool,
+    removal_policy: TweenTargetRemovalPolicy,
+) -> Entity {
+    let start_opacity = if delta { 0.3 } else { 0.0 };
+    let target = app.world_mut().spawn(Opacity(start_opacity + 0.5)).id();
+    let mut state = target.into_target().state(());
+    app.world_mut()
+        .commands()
+        .spawn(removal_policy)
+        .animation()
+        .insert(tween(
+            Duration::from_secs(2),
+            EaseKind::Linear,
+            state.with(move |_state| Fade { delta }),
+        ));
+    app.update();
+
+    let tween_entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ComponentTween<Fade>>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .entity_mut(tween_entity)
+        .insert(TweenPreviousValue(0.5));
+    target
+}
+
+fn log(log_me: String) {
+    println!("{}", log_me);
+}
+
+#[derive(Component)]
+struct Opacity(f32);
+
+#[derive(Clone, Copy, Debug)]
+struct Fade {
+    delta: bool,
+}
+
+impl Interpolator for Fade {
+    type Item = Opacity;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32, previous_value: f32) {
+        if self.delta {
+            item.0 += value - previous_value;
+        } else {
+            item.0 = value;
+        }
+    }
+}